@@ -0,0 +1,310 @@
+use utils::error::{QuestError, Result};
+use utils::runner::{CommandArgs, CommandError, CommandResult, Quest};
+
+fn main() {
+    Quest::new()
+        .part(1, part1)
+        .example("inputs/part1_example.txt")
+        .expect(5)
+        .input("inputs/part1.txt")
+        .expect(1437)
+        .part(2, part2)
+        .example("inputs/part2_example.txt")
+        .expect(28)
+        .input("inputs/part2.txt")
+        .expect(5669)
+        .part(3, part3)
+        .example("inputs/part3_example.txt")
+        .expect(30)
+        .input("inputs/part3.txt")
+        .expect(28073)
+        .command("--breakdown", "[--csv]", print_breakdown)
+        .run();
+}
+
+const COSTS: &[(char, usize)] = &[('A', 0), ('B', 1), ('C', 3), ('D', 5)];
+
+const BATTLES: [Battle; 3] = [
+    Battle::new(1, &[0]),
+    Battle::new(2, &[2]),
+    Battle::new(3, &[6, 2]),
+];
+
+fn part1(input: &str) -> Result<usize> {
+    BATTLES[0].score(input)
+}
+
+fn part2(input: &str) -> Result<usize> {
+    BATTLES[1].score(input)
+}
+
+fn part3(input: &str) -> Result<usize> {
+    BATTLES[2].score(input)
+}
+
+/// Prints the groups of a part's battle as a table, or as CSV with `--csv`.
+fn print_breakdown(args: CommandArgs) -> CommandResult {
+    let battle = (args.part as usize)
+        .checked_sub(1)
+        .and_then(|i| BATTLES.get(i))
+        .ok_or(CommandError::Usage)?;
+    let groups = battle.breakdown(&args.input)?;
+
+    match args.options.as_slice() {
+        [csv] if csv == "--csv" => print!("{}", breakdown_csv(&groups)),
+        [] => print!("{}", breakdown_table(&groups)),
+        _ => return Err(CommandError::Usage),
+    }
+    Ok(())
+}
+
+/// Score of one battle group.
+#[derive(Debug, PartialEq, Eq)]
+struct GroupScore {
+    creatures: String,
+    /// Potions for the creatures themselves.
+    base: usize,
+    bonus: usize,
+    /// Potions for this and all previous groups.
+    total: usize,
+}
+
+fn breakdown_table(groups: &[GroupScore]) -> String {
+    let width = groups
+        .iter()
+        .map(|group| group.creatures.len())
+        .max()
+        .unwrap_or(0)
+        .max("Creatures".len());
+    let mut res = format!(
+        "{:>5}  {:<width$}  {:>5}  {:>5}  {:>7}\n",
+        "Group", "Creatures", "Base", "Bonus", "Total"
+    );
+    for (i, group) in groups.iter().enumerate() {
+        res += &format!(
+            "{:>5}  {:<width$}  {:>5}  {:>5}  {:>7}\n",
+            i + 1,
+            group.creatures,
+            group.base,
+            group.bonus,
+            group.total
+        );
+    }
+    res
+}
+
+fn breakdown_csv(groups: &[GroupScore]) -> String {
+    let mut res = String::from("group,creatures,base,bonus,total\n");
+    for (i, group) in groups.iter().enumerate() {
+        res += &format!(
+            "{},{},{},{},{}\n",
+            i + 1,
+            group.creatures,
+            group.base,
+            group.bonus,
+            group.total
+        );
+    }
+    res
+}
+
+/// Rules for scoring a line of creatures that fight in groups.
+struct Battle<'a> {
+    /// Potions needed per creature.
+    costs: &'a [(char, usize)],
+    /// Marks a slot without a creature.
+    empty: char,
+    group_size: usize,
+    /// Extra potions for a group, indexed by its number of empty slots.
+    /// Groups with more empty slots than listed get none.
+    bonus: &'a [usize],
+}
+
+impl<'a> Battle<'a> {
+    const fn new(group_size: usize, bonus: &'a [usize]) -> Self {
+        Self {
+            costs: COSTS,
+            empty: 'x',
+            group_size,
+            bonus,
+        }
+    }
+
+    /// Returns the potions needed for all groups.
+    ///
+    /// Fails on unknown creatures and if the last group is incomplete.
+    fn score(&self, input: &str) -> Result<usize> {
+        let groups = self.breakdown(input)?;
+        Ok(groups.last().map_or(0, |group| group.total))
+    }
+
+    /// Returns the score of every group, see [`Battle::score`].
+    fn breakdown(&self, input: &str) -> Result<Vec<GroupScore>> {
+        let slots: Vec<char> = input.trim_end().chars().collect();
+        let mut groups = Vec::new();
+        let mut total = 0;
+
+        for (i, group) in slots.chunks(self.group_size).enumerate() {
+            if group.len() < self.group_size {
+                return Err(QuestError::invalid(format!(
+                    "Last group has {} of {} slots",
+                    group.len(),
+                    self.group_size
+                )));
+            }
+            let mut base = 0;
+            let mut empty = 0;
+            for (j, &slot) in group.iter().enumerate() {
+                if slot == self.empty {
+                    empty += 1;
+                    continue;
+                }
+                base += self.cost(slot).ok_or_else(|| {
+                    QuestError::parse(format!(
+                        "Unknown creature {slot:?} at position {}",
+                        i * self.group_size + j
+                    ))
+                })?;
+            }
+            let bonus = self.bonus.get(empty).copied().unwrap_or(0);
+            total += base + bonus;
+            groups.push(GroupScore {
+                creatures: group.iter().collect(),
+                base,
+                bonus,
+                total,
+            });
+        }
+        Ok(groups)
+    }
+
+    fn cost(&self, creature: char) -> Option<usize> {
+        self.costs
+            .iter()
+            .find(|&&(c, _)| c == creature)
+            .map(|&(_, cost)| cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1_example.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok(5));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part1.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok(1437));
+        }
+    }
+
+    mod part2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part2_example.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(28));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part2.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(5669));
+        }
+
+        #[test]
+        fn incomplete_pair() {
+            let res = part2("AxB");
+            assert_eq!(
+                res,
+                Err(QuestError::Invalid(
+                    "Last group has 1 of 2 slots".to_string()
+                ))
+            );
+        }
+
+        #[test]
+        fn trailing_newline() {
+            // Ax, BC + 2, DD + 2, CA + 2
+            let res = part2("AxBCDDCA\n");
+            assert_eq!(res, Ok(23));
+        }
+
+        #[test]
+        fn unknown_creature() {
+            let res = part2("AxBCDECA");
+            assert_eq!(
+                res,
+                Err(QuestError::Parse(
+                    "Unknown creature 'E' at position 5".to_string()
+                ))
+            );
+        }
+    }
+
+    mod part3 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part3_example.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(30));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part3.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(28073));
+        }
+
+        #[test]
+        fn breakdown() {
+            let input = input::read_file("inputs/part3_example.txt");
+            let groups = BATTLES[2].breakdown(&input).unwrap();
+            assert_eq!(groups.len(), 4);
+            assert_eq!(
+                groups[2],
+                GroupScore {
+                    creatures: "BCD".to_string(),
+                    base: 9,
+                    bonus: 6,
+                    total: 22,
+                }
+            );
+            assert_eq!(
+                breakdown_table(&groups[..2]),
+                "\
+Group  Creatures   Base  Bonus    Total
+    1  xBx            1      0        1
+    2  AAA            0      6        7
+"
+            );
+            assert_eq!(
+                breakdown_csv(&groups),
+                "\
+group,creatures,base,bonus,total
+1,xBx,1,0,1
+2,AAA,0,6,7
+3,BCD,9,6,22
+4,xCC,6,2,30
+"
+            );
+        }
+    }
+}
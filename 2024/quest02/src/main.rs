@@ -0,0 +1,429 @@
+use std::collections::HashSet;
+use std::fs;
+use unicode_segmentation::UnicodeSegmentation;
+use utils::aho_corasick::AhoCorasick;
+use utils::colors::{BLUE, CYAN, GREEN, MAGENTA, RED, RESET, YELLOW};
+use utils::error::{QuestError, Result};
+use utils::grid::{Grid, Heading, Position, WordSearch};
+use utils::parse::note::FromNote;
+use utils::runner::{CommandArgs, CommandError, CommandResult, Quest};
+
+fn main() {
+    Quest::new()
+        .part(1, part1)
+        .example("inputs/part1_example.txt")
+        .expect(12)
+        .input("inputs/part1.txt")
+        .expect(34)
+        .part(2, part2)
+        .example("inputs/part2_example.txt")
+        .expect(42)
+        .input("inputs/part2.txt")
+        .expect(5165)
+        .part(3, part3)
+        .example("inputs/part3_example.txt")
+        .expect(10)
+        .input("inputs/part3.txt")
+        .expect(12076)
+        .command(
+            "--highlight",
+            "[--legend] [--text FILE] [--html FILE]",
+            print_highlight,
+        )
+        .run();
+}
+
+fn part1(input: &str) -> Result<usize> {
+    let note = parse_note(input)?;
+    let runes = AhoCorasick::new(note.runes.iter().map(|rune| symbols(rune)));
+
+    Ok(runes.find_overlapping(symbols(&note.inscription)).len())
+}
+
+fn part2(input: &str) -> Result<usize> {
+    let note = parse_note(input)?;
+    Ok(text_marks(&note).used())
+}
+
+fn part3(input: &str) -> Result<usize> {
+    let note = parse_note(input)?;
+    Ok(grid_marks(&note)?.used())
+}
+
+/// Symbols of an inscription and where the runes were found on them.
+struct Marks<'a> {
+    rows: Vec<Vec<&'a str>>,
+    runes: &'a [String],
+    /// Rune index, reading heading and covered cells of every match, with
+    /// the cells in reading order.
+    matches: Vec<(usize, Heading, Vec<Position>)>,
+}
+
+impl Marks<'_> {
+    /// Returns the number of symbols covered by any match.
+    fn used(&self) -> usize {
+        self.matches
+            .iter()
+            .flat_map(|(_, _, cells)| cells)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Returns the rune of the first match covering each symbol.
+    fn owners(&self) -> Vec<Vec<Option<usize>>> {
+        let mut owners: Vec<Vec<Option<usize>>> =
+            self.rows.iter().map(|row| vec![None; row.len()]).collect();
+        for (rune, _, cells) in &self.matches {
+            for pos in cells {
+                owners[pos.row][pos.col].get_or_insert(*rune);
+            }
+        }
+        owners
+    }
+}
+
+/// Finds the runes forwards and backwards in each line of the inscription.
+fn text_marks(note: &Note) -> Marks<'_> {
+    let runes = both_ways(&note.runes);
+    let rows = rows(&note.inscription);
+    let mut matches = Vec::new();
+
+    for (row, line) in rows.iter().enumerate() {
+        for m in runes.find_overlapping(line.iter().copied()) {
+            let mut cells: Vec<Position> = m.span().map(|col| Position::new(row, col)).collect();
+            let heading = if m.pattern < note.runes.len() {
+                Heading::Right
+            } else {
+                cells.reverse();
+                Heading::Left
+            };
+            matches.push((m.pattern % note.runes.len(), heading, cells));
+        }
+    }
+    Marks {
+        rows,
+        runes: &note.runes,
+        matches,
+    }
+}
+
+/// Finds the runes in all straight directions of the inscription grid,
+/// wrapping around rows only.
+fn grid_marks(note: &Note) -> Result<Marks<'_>> {
+    let grid = parse_grid(&note.inscription)?;
+    let search = WordSearch::new(&Heading::STRAIGHT).wrap_horizontal(true);
+    let matches = search
+        .find_matches(&grid, note.runes.iter().map(|rune| symbols(rune)))
+        .into_iter()
+        .map(|m| (m.word, m.heading, m.cells))
+        .collect();
+
+    Ok(Marks {
+        rows: rows(&note.inscription),
+        runes: &note.runes,
+        matches,
+    })
+}
+
+/// Terminal and HTML colors of the runes, assigned by rune index.
+const PALETTE: [(&str, &str); 6] = [
+    (RED, "#cc3333"),
+    (GREEN, "#2e9e2e"),
+    (YELLOW, "#b08800"),
+    (BLUE, "#3366cc"),
+    (MAGENTA, "#aa33aa"),
+    (CYAN, "#1a9999"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    /// Matched symbols in the color of their rune.
+    Terminal,
+    /// Matched symbols only, all others replaced by `.`.
+    Text,
+    /// A standalone page with colored matched symbols.
+    Html,
+}
+
+/// Renders the inscription with its matched symbols highlighted and, with
+/// `legend`, a line per rune listing where it was found.
+fn render(marks: &Marks, format: Format, legend: bool) -> String {
+    let paint = |rune: usize, text: &str| {
+        let (term, html) = PALETTE[rune % PALETTE.len()];
+        match format {
+            Format::Terminal => format!("{term}{text}{RESET}"),
+            Format::Text => text.to_string(),
+            Format::Html => format!("<span style=\"color: {html}\">{}</span>", escape(text)),
+        }
+    };
+    let mut res = String::new();
+    if format == Format::Html {
+        res += "<!DOCTYPE html>\n<html>\n<body>\n<pre>\n";
+    }
+
+    for (symbols, owners) in marks.rows.iter().zip(marks.owners()) {
+        for (&symbol, owner) in symbols.iter().zip(owners) {
+            res += &match (owner, format) {
+                (Some(rune), _) => paint(rune, symbol),
+                (None, Format::Text) => ".".to_string(),
+                (None, Format::Html) => escape(symbol),
+                (None, Format::Terminal) => symbol.to_string(),
+            };
+        }
+        res += "\n";
+    }
+
+    if legend {
+        res += "\n";
+        for (rune, word) in marks.runes.iter().enumerate() {
+            let found: Vec<String> = marks
+                .matches
+                .iter()
+                .filter(|(other, _, _)| *other == rune)
+                .map(|(_, heading, cells)| {
+                    format!("{}:{} {}", cells[0].row, cells[0].col, arrow(*heading))
+                })
+                .collect();
+            let found = if found.is_empty() {
+                "-".to_string()
+            } else {
+                found.join(", ")
+            };
+            res += &format!("{}: {found}\n", paint(rune, word));
+        }
+    }
+
+    if format == Format::Html {
+        res += "</pre>\n</body>\n</html>\n";
+    }
+    res
+}
+
+fn arrow(heading: Heading) -> char {
+    match heading {
+        Heading::Up => '↑',
+        Heading::UpRight => '↗',
+        Heading::Right => '→',
+        Heading::DownRight => '↘',
+        Heading::Down => '↓',
+        Heading::DownLeft => '↙',
+        Heading::Left => '←',
+        Heading::UpLeft => '↖',
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Prints the inscription of part 2 or 3 with the symbols counted as used
+/// highlighted, and writes it to the files given by `--text` and `--html`.
+fn print_highlight(args: CommandArgs) -> CommandResult {
+    let note = parse_note(&args.input)?;
+    let marks = match args.part {
+        2 => text_marks(&note),
+        3 => grid_marks(&note)?,
+        _ => return Err(CommandError::Usage),
+    };
+
+    let mut legend = false;
+    let mut files = Vec::new();
+    let mut rest = args.options.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--legend" => legend = true,
+            "--text" => files.push((Format::Text, rest.next().ok_or(CommandError::Usage)?)),
+            "--html" => files.push((Format::Html, rest.next().ok_or(CommandError::Usage)?)),
+            _ => return Err(CommandError::Usage),
+        }
+    }
+
+    print!("{}", render(&marks, Format::Terminal, legend));
+    for (format, path) in files {
+        fs::write(path, render(&marks, format, legend))
+            .map_err(|err| QuestError::input(format!("{path}: {err}")))?;
+    }
+    Ok(())
+}
+
+/// Returns a matcher for the runes read forwards and backwards.
+fn both_ways(runes: &[String]) -> AhoCorasick<&str> {
+    let forward = runes.iter().map(|rune| symbols(rune));
+    let backward = runes
+        .iter()
+        .map(|rune| symbols(rune).into_iter().rev().collect());
+    AhoCorasick::new(forward.chain(backward))
+}
+
+/// Splits text into its symbols, the user-perceived characters (extended
+/// grapheme clusters), so an accented letter stays one symbol even when it
+/// is written as a base letter and a combining mark.
+fn symbols(text: &str) -> Vec<&str> {
+    text.graphemes(true).collect()
+}
+
+fn rows(text: &str) -> Vec<Vec<&str>> {
+    text.lines().map(symbols).collect()
+}
+
+#[derive(FromNote)]
+struct Note {
+    #[note(prefix = "WORDS:", sep = ",")]
+    runes: Vec<String>,
+    #[note(section = 1)]
+    inscription: String,
+}
+
+fn parse_note(input: &str) -> Result<Note> {
+    let note = Note::from_note(input).map_err(|err| QuestError::from(err).context("note"))?;
+    if note.runes.iter().any(String::is_empty) {
+        return Err(QuestError::invalid("Empty rune word"));
+    }
+    if note.inscription.is_empty() {
+        return Err(QuestError::invalid("Empty inscription"));
+    }
+    Ok(note)
+}
+
+fn parse_grid(inscription: &str) -> Result<Grid<&str>> {
+    let rows = rows(inscription);
+    if rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err(QuestError::invalid("Inscription rows differ in length"));
+    }
+    Ok(Grid::new(rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1_example.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok(12));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part1.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok(34));
+        }
+
+        #[test]
+        fn overlapping() {
+            let res = part1("WORDS:ABA,BA\n\nABABA");
+            assert_eq!(res, Ok(4));
+        }
+
+        #[test]
+        fn multi_byte() {
+            let res = part1("WORDS:ÄÖ,ΩΨ,火\n\nAÄÖÜ ΩΨΩ 火火");
+            assert_eq!(res, Ok(4));
+        }
+    }
+
+    mod part2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part2_example.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(42));
+        }
+
+        #[test]
+        fn example_crlf() {
+            let input = input::read_file("inputs/part2_example.txt").replace('\n', "\r\n");
+            let res = part2(&input);
+            assert_eq!(res, Ok(42));
+        }
+
+        #[test]
+        fn accented() {
+            let res = part2("WORDS:ÉTÉ,ÆØ\n\nxÉTÉx ØÆ");
+            assert_eq!(res, Ok(5));
+        }
+
+        #[test]
+        fn combining_marks() {
+            // "e\u{301}" is one symbol, "é" spelled as "e" and a combining
+            // accent, and must stay in one piece when the rune is reversed.
+            let res = part2("WORDS:CAFE\u{301}\n\nE\u{301}FAC CAFE");
+            assert_eq!(res, Ok(4));
+            assert_eq!(symbols("CAFE\u{301}").len(), 4);
+        }
+
+        #[test]
+        fn highlight() {
+            let note = parse_note("WORDS:AB,<y\n\nxABx\nBA<y").unwrap();
+            let marks = text_marks(&note);
+            assert_eq!(marks.used(), 6);
+            assert_eq!(
+                render(&marks, Format::Text, true),
+                ".AB.\nBA<y\n\nAB: 0:1 →, 1:1 ←\n<y: 1:2 →\n"
+            );
+
+            let terminal = render(&marks, Format::Terminal, false);
+            assert!(terminal.starts_with(&format!("x{RED}A{RESET}{RED}B{RESET}x\n")));
+            let html = render(&marks, Format::Html, false);
+            assert!(html.contains("<span style=\"color: #2e9e2e\">&lt;</span>"));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part2.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(5165));
+        }
+    }
+
+    mod part3 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part3_example.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(10));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part3.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(12076));
+        }
+
+        #[test]
+        fn highlight() {
+            let note = parse_note("WORDS:AB,Cy\n\nyBC\nBAz\nzBz").unwrap();
+            let marks = grid_marks(&note).unwrap();
+            assert_eq!(marks.used(), 6);
+            assert_eq!(
+                render(&marks, Format::Text, true),
+                "yBC\nBA.\n.B.\n\nAB: 1:1 ↑, 1:1 ↓, 1:1 ←\nCy: 0:2 →\n"
+            );
+        }
+
+        #[test]
+        fn multi_byte() {
+            let res = part3("WORDS:日本,O\u{308}L\n\n本x日\nLxO\u{308}\nxxx");
+            assert_eq!(res, Ok(4));
+        }
+
+        #[test]
+        fn ragged_inscription() {
+            let res = part3("WORDS:THE\n\nTHE\nOWE\nMES\nROD\nRODEO");
+            assert!(matches!(res, Err(QuestError::Invalid(_))));
+        }
+    }
+}
@@ -0,0 +1,516 @@
+use utils::colors::{BLUE, CYAN, GREEN, MAGENTA, RED, RESET, YELLOW};
+use utils::error::{QuestError, Result};
+use utils::grid::{Grid, Metric, Neighborhood, Position};
+use utils::runner::{CommandArgs, CommandError, CommandResult, Quest};
+
+fn main() {
+    Quest::new()
+        .part(1, part1and2)
+        .example("inputs/part1_example.txt")
+        .expect(35)
+        .input("inputs/part1.txt")
+        .expect(124)
+        .part(2, part1and2)
+        .input("inputs/part2.txt")
+        .expect(2668)
+        .part(3, part3)
+        .example("inputs/part3_example.txt")
+        .expect(29)
+        .input("inputs/part3.txt")
+        .expect(10190)
+        .command(
+            "--render",
+            "[--gradient] [--rounds] [--neighborhood von-neumann|moore|hex|'R,C;R,C;...'] [--edge air|ignore]",
+            print_render,
+        )
+        .run();
+}
+
+fn part1and2(input: &str) -> Result<usize> {
+    solve(input, &Rule::orthogonal())
+}
+
+fn part3(input: &str) -> Result<usize> {
+    solve(input, &Rule::all_around())
+}
+
+/// Returns how many blocks of earth can be dug out under `rule`.
+fn solve(input: &str, rule: &Rule) -> Result<usize> {
+    Ok(Depths::new(&parse_map(input)?, rule)?.sum())
+}
+
+/// Parses the map into a grid that is true for earth (`#`) and false for
+/// air (`.`).
+fn parse_map(input: &str) -> Result<Grid<bool>> {
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(col, c)| match c {
+                    '.' => Ok(false),
+                    '#' => Ok(true),
+                    _ => Err(QuestError::parse(format!(
+                        "Invalid character {c:?} in map at row {row}, column {col}"
+                    ))),
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if rows.is_empty() {
+        return Err(QuestError::invalid("Empty map"));
+    }
+    if rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err(QuestError::invalid("Map rows differ in length"));
+    }
+    Ok(Grid::new(rows))
+}
+
+/// When a block of earth can be dug out: once all cells in its
+/// `neighborhood` are at least as deep as the block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Rule {
+    neighborhood: Neighborhood,
+    edge: Edge,
+}
+
+/// How cells beyond the edge of the map count as neighbors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edge {
+    /// As air, so earth next to the edge is one block deep.
+    Air,
+    /// Not at all, so only the neighbors on the map hold back digging.
+    Ignored,
+}
+
+impl Rule {
+    fn new(neighborhood: impl Into<Neighborhood>, edge: Edge) -> Self {
+        let neighborhood = neighborhood.into();
+        Self { neighborhood, edge }
+    }
+
+    /// Rule of parts 1 and 2, with the 4 orthogonal neighbors.
+    fn orthogonal() -> Self {
+        Self::new(Metric::Manhattan, Edge::Air)
+    }
+
+    /// Rule of part 3, with all 8 neighbors including diagonals.
+    fn all_around() -> Self {
+        Self::new(Metric::Chebyshev, Edge::Air)
+    }
+
+    /// Returns the rule of `part`.
+    fn of_part(part: usize) -> Option<Self> {
+        match part {
+            1 | 2 => Some(Self::orthogonal()),
+            3 => Some(Self::all_around()),
+            _ => None,
+        }
+    }
+}
+
+/// How many blocks of earth can be dug out at each cell, `None` for air.
+struct Depths(Grid<Option<usize>>);
+
+impl Depths {
+    /// Computes the depth of every earth cell under `rule` with one BFS from
+    /// all earth next to air at once.
+    ///
+    /// Earth is one block deeper than its shallowest neighbor, so depths
+    /// spread to the cells that have a cell as neighbor, i.e. along the
+    /// reversed neighborhood.
+    fn new(map: &Grid<bool>, rule: &Rule) -> Result<Self> {
+        // Earth with air in its neighborhood is one block deep, so start
+        // from there instead of padding the map with air, which could be far
+        // bigger than the map for a stencil with a long reach.
+        let offsets = rule.neighborhood.offsets();
+        let shallow = map
+            .iter()
+            .filter(|(pos, &earth)| {
+                earth
+                    && offsets.iter().any(|&(row, col)| {
+                        match pos.offset(row, col).and_then(|pos| map.get(&pos)) {
+                            Some(&earth) => !earth,
+                            None => rule.edge == Edge::Air,
+                        }
+                    })
+            })
+            .map(|(pos, _)| pos);
+        let steps = rule.neighborhood.reversed();
+        let field = map.distance_field_by(shallow, |_, &earth| earth, steps.offsets());
+
+        let mut depths = Vec::with_capacity(map.height);
+        for row in 0..map.height {
+            let mut line = Vec::with_capacity(map.width);
+            for col in 0..map.width {
+                let depth = match map.get(&Position::new(row, col)) {
+                    Some(true) => Some(
+                        field
+                            .get(&Position::new(row, col))
+                            .copied()
+                            .flatten()
+                            .map(|dist| dist + 1)
+                            .ok_or_else(|| {
+                                QuestError::no_solution(format!(
+                                    "Earth at row {row}, column {col} can be dug forever"
+                                ))
+                            })?,
+                    ),
+                    _ => None,
+                };
+                line.push(depth);
+            }
+            depths.push(line);
+        }
+        Ok(Depths(Grid::new(depths)))
+    }
+
+    fn sum(&self) -> usize {
+        self.0.iter().filter_map(|(_, depth)| *depth).sum()
+    }
+}
+
+/// Scale of the symbols for depths when rendering a map.
+///
+/// Depths are shown as the base-36 digits 1 to z. Once the deepest cell is
+/// deeper than 35, each digit stands for a range of equally many depths.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scale {
+    /// Plain digits.
+    Base36,
+    /// The base-36 digits, colored from shallow (blue) to deep (magenta).
+    Gradient,
+}
+
+/// Gradient colors from shallow to deep.
+const GRADIENT: [&str; 6] = [BLUE, CYAN, GREEN, YELLOW, RED, MAGENTA];
+
+impl Depths {
+    fn max(&self) -> usize {
+        self.0
+            .iter()
+            .filter_map(|(_, depth)| *depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the depths after `round` rounds of digging, where each round
+    /// digs one block out of every cell that is not done yet.
+    fn after(&self, round: usize) -> Depths {
+        Depths(self.0.map(|_, depth| depth.map(|depth| depth.min(round))))
+    }
+
+    /// Renders the map with `.` for air and a symbol per depth, scaled to
+    /// the deepest cell `max`.
+    fn render(&self, scale: Scale, max: usize) -> String {
+        let mut res = String::new();
+        for row in 0..self.0.height {
+            for col in 0..self.0.width {
+                match self.0.get(&Position::new(row, col)).unwrap() {
+                    Some(depth) => res += &symbol(*depth, scale, max),
+                    None => res.push('.'),
+                }
+            }
+            res.push('\n');
+        }
+        res
+    }
+}
+
+/// Returns how many depths each digit stands for, so that all depths up to
+/// `max` fit into the 35 digits.
+fn bucket_size(max: usize) -> usize {
+    max.div_ceil(35).max(1)
+}
+
+fn symbol(depth: usize, scale: Scale, max: usize) -> String {
+    let bucket = depth.div_ceil(bucket_size(max)) as u32;
+    let digit = char::from_digit(bucket, 36).expect("Depths up to max fit into the digits");
+    match scale {
+        Scale::Base36 => digit.to_string(),
+        Scale::Gradient => {
+            let color = GRADIENT[(depth - 1) * GRADIENT.len() / max.max(1)];
+            format!("{color}{digit}{RESET}")
+        }
+    }
+}
+
+/// Renders a table of the depths, or ranges of depths, up to `max` and
+/// their symbols.
+fn legend(scale: Scale, max: usize) -> String {
+    let size = bucket_size(max);
+    let buckets: Vec<(usize, String)> = (1..=max.div_ceil(size))
+        .map(|bucket| {
+            let low = (bucket - 1) * size + 1;
+            let high = (bucket * size).min(max);
+            match low == high {
+                true => (low, low.to_string()),
+                false => (low, format!("{low}-{high}")),
+            }
+        })
+        .collect();
+    let width = buckets
+        .iter()
+        .map(|(_, label)| label.len())
+        .max()
+        .unwrap_or(0);
+    let mut depths = String::from("Depth ");
+    let mut symbols = String::from("Symbol");
+    for (low, label) in buckets {
+        depths += &format!(" {label:>width$}");
+        // Symbols are one column wide, but may carry color codes.
+        symbols += &" ".repeat(width);
+        symbols += &symbol(low, scale, max);
+    }
+    format!("{depths}\n{symbols}\n")
+}
+
+/// Prints the depths of a part's map and a legend. With `--rounds`, the map
+/// is printed after every round of digging instead of only at the end.
+fn print_render(args: CommandArgs) -> CommandResult {
+    let mut rule = Rule::of_part(args.part.into()).ok_or(CommandError::Usage)?;
+    let mut scale = Scale::Base36;
+    let mut rounds = false;
+    let mut rest = args.options.iter();
+    while let Some(arg) = rest.next() {
+        match (arg.as_str(), rest.as_slice().first().map(String::as_str)) {
+            ("--gradient", _) => scale = Scale::Gradient,
+            ("--rounds", _) => rounds = true,
+            ("--neighborhood", Some(neighborhood)) => {
+                rule.neighborhood = neighborhood.parse().map_err(|_| CommandError::Usage)?;
+                rest.next();
+            }
+            ("--edge", Some(edge)) => {
+                rule.edge = match edge {
+                    "air" => Edge::Air,
+                    "ignore" => Edge::Ignored,
+                    _ => return Err(CommandError::Usage),
+                };
+                rest.next();
+            }
+            _ => return Err(CommandError::Usage),
+        }
+    }
+
+    let depths = Depths::new(&parse_map(&args.input)?, &rule)?;
+    let max = depths.max();
+    if rounds && max > 0 {
+        for round in 1..max {
+            println!("Round {round}:\n{}", depths.after(round).render(scale, max));
+        }
+        println!("Round {max}:");
+    }
+    print!("{}", depths.render(scale, max));
+    if max > 0 {
+        print!("\n{}", legend(scale, max));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1_example.txt");
+            let res = part1and2(&input);
+            assert_eq!(res, Ok(35));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part1.txt");
+            let res = part1and2(&input);
+            assert_eq!(res, Ok(124));
+        }
+
+        #[test]
+        fn invalid_character() {
+            let res = part1and2("..#\n.?#");
+            assert!(matches!(res, Err(QuestError::Parse(_))));
+        }
+
+        #[test]
+        fn edge_is_air() {
+            let res = part1and2("###\n###\n###");
+            assert_eq!(res, Ok(10));
+        }
+    }
+
+    mod part2 {
+        use super::*;
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part2.txt");
+            let res = part1and2(&input);
+            assert_eq!(res, Ok(2668));
+        }
+    }
+
+    mod rules {
+        use super::*;
+
+        fn depths(map: &str, rule: &Rule) -> Result<Vec<Option<usize>>> {
+            let depths = Depths::new(&parse_map(map)?, rule)?;
+            Ok(depths.0.iter().map(|(_, depth)| *depth).collect())
+        }
+
+        #[test]
+        fn hex() {
+            // Axial coordinates: the center of the second row has all six
+            // neighbors on the map, the cell to its right has only four.
+            let rule = Rule::new(Metric::Hex, Edge::Air);
+            let res = depths(".##\n###\n##.", &rule);
+            assert_eq!(
+                res,
+                Ok(vec![
+                    None,
+                    Some(1),
+                    Some(1),
+                    Some(1),
+                    Some(2),
+                    Some(1),
+                    Some(1),
+                    Some(1),
+                    None
+                ])
+            );
+        }
+
+        #[test]
+        fn custom() {
+            // Dug once the block to the right and the one below are as deep.
+            let neighborhood = Neighborhood::new(vec![(0, 1), (1, 0)]);
+            let rule = Rule::new(neighborhood, Edge::Air);
+            let res = depths("###\n###", &rule);
+            assert_eq!(
+                res,
+                Ok(vec![Some(2), Some(2), Some(1), Some(1), Some(1), Some(1)])
+            );
+        }
+
+        #[test]
+        fn long_reach() {
+            // Steps beyond the edge count as air without building a map
+            // that reaches that far.
+            let neighborhood = Neighborhood::new(vec![(0, 1), (0, 100_000)]);
+            let rule = Rule::new(neighborhood, Edge::Air);
+            let res = depths("###", &rule);
+            assert_eq!(res, Ok(vec![Some(1), Some(1), Some(1)]));
+
+            let rule = Rule::new(Neighborhood::new(vec![(0, 1)]), Edge::Air);
+            let res = depths("###", &rule);
+            assert_eq!(res, Ok(vec![Some(3), Some(2), Some(1)]));
+        }
+
+        #[test]
+        fn edge_is_ignored() {
+            let rule = Rule::new(Metric::Manhattan, Edge::Ignored);
+            let res = depths("###\n##.", &rule);
+            assert_eq!(
+                res,
+                Ok(vec![Some(3), Some(2), Some(1), Some(2), Some(1), None])
+            );
+
+            let res = depths("##\n##", &rule);
+            assert_eq!(
+                res,
+                Err(QuestError::no_solution(
+                    "Earth at row 0, column 0 can be dug forever"
+                ))
+            );
+        }
+    }
+
+    mod part3 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part3_example.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(29));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part3.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(10190));
+        }
+
+        #[test]
+        fn render() {
+            let map = Grid::new(vec![vec![true; 23]; 23]);
+            let depths = Depths::new(&map, &Rule::all_around()).unwrap();
+            let max = depths.max();
+            assert_eq!(max, 12);
+
+            let rendered = depths.render(Scale::Base36, max);
+            assert_eq!(rendered.lines().nth(11), Some("123456789abcba987654321"));
+            let rendered = depths.after(10).render(Scale::Base36, max);
+            assert_eq!(rendered.lines().nth(11), Some("123456789aaaaa987654321"));
+            assert_eq!(
+                symbol(12, Scale::Gradient, max),
+                format!("{MAGENTA}c{RESET}")
+            );
+        }
+
+        #[test]
+        fn legend() {
+            assert_eq!(
+                super::legend(Scale::Base36, 11),
+                "Depth   1  2  3  4  5  6  7  8  9 10 11\nSymbol  1  2  3  4  5  6  7  8  9  a  b\n"
+            );
+        }
+
+        #[test]
+        fn deeper_than_digits() {
+            assert_eq!(symbol(35, Scale::Base36, 35), "z");
+            // Two depths per digit from 36 to 70.
+            assert_eq!(symbol(1, Scale::Base36, 36), "1");
+            assert_eq!(symbol(2, Scale::Base36, 36), "1");
+            assert_eq!(symbol(3, Scale::Base36, 36), "2");
+            assert_eq!(symbol(36, Scale::Base36, 36), "i");
+            assert_eq!(symbol(70, Scale::Base36, 70), "z");
+            assert_eq!(symbol(1000, Scale::Base36, 1000), "z");
+
+            let legend = super::legend(Scale::Base36, 5 * 35 + 1);
+            let mut lines = legend.lines();
+            assert!(lines
+                .next()
+                .unwrap()
+                .starts_with("Depth      1-6    7-12   13-18"));
+            assert!(lines
+                .next()
+                .unwrap()
+                .starts_with("Symbol       1       2       3"));
+            assert!(legend.contains(" 175-176\n"));
+        }
+
+        #[test]
+        fn stress() {
+            // A solid square, where every cell is as deep as its distance
+            // to the nearest edge under both metrics.
+            let n = 1000;
+            let map = Grid::new(vec![vec![true; n]; n]);
+            let expected: usize = (0..n)
+                .flat_map(|row| {
+                    (0..n).map(move |col| row.min(col).min(n - 1 - row).min(n - 1 - col) + 1)
+                })
+                .sum();
+
+            let rule = Rule::orthogonal();
+            assert_eq!(Depths::new(&map, &rule).unwrap().sum(), expected);
+            let rule = Rule::all_around();
+            assert_eq!(Depths::new(&map, &rule).unwrap().sum(), expected);
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use utils::error::{QuestError, Result};
+use utils::runner::{CommandArgs, CommandError, CommandResult, Quest};
+
+fn main() {
+    Quest::new()
+        .part(1, part1and2)
+        .example("inputs/part1_example.txt")
+        .expect(10)
+        .input("inputs/part1.txt")
+        .expect(84)
+        .part(2, part1and2)
+        .input("inputs/part2.txt")
+        .expect(919880)
+        .part(3, part3)
+        .example("inputs/part3_example.txt")
+        .expect(8)
+        .input("inputs/part3.txt")
+        .expect(129441494)
+        .command("--plan", "", print_plan)
+        .run();
+}
+
+/// Nails can only be hammered down, one level per hit.
+const HAMMER: Cost = Cost::new(Some(1), None);
+/// Nails can be hammered down or pulled up, one level per hit.
+const HAMMER_AND_PULL: Cost = Cost::new(Some(1), Some(1));
+
+fn part1and2(input: &str) -> Result<usize> {
+    Ok(solve(&get_nails(input)?, HAMMER)?.hits)
+}
+
+fn part3(input: &str) -> Result<usize> {
+    Ok(solve(&get_nails(input)?, HAMMER_AND_PULL)?.hits)
+}
+
+/// Prints the target level and hits of a part.
+fn print_plan(args: CommandArgs) -> CommandResult {
+    let cost = match args.part {
+        1 | 2 => HAMMER,
+        3 => HAMMER_AND_PULL,
+        _ => return Err(CommandError::Usage),
+    };
+    if !args.options.is_empty() {
+        return Err(CommandError::Usage);
+    }
+    let plan = solve(&get_nails(&args.input)?, cost)?;
+
+    println!("Target level: {}", plan.target);
+    println!("Hits: {}", plan.hits);
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Nail {
+    height: usize,
+    /// Factor on the hits for this nail, e.g. for a nail that is harder to
+    /// move or stands for several nails of the same height.
+    weight: usize,
+}
+
+/// Hits to move a nail by one level, `None` if it can't move that way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Cost {
+    down: Option<usize>,
+    up: Option<usize>,
+}
+
+impl Cost {
+    const fn new(down: Option<usize>, up: Option<usize>) -> Self {
+        Self { down, up }
+    }
+}
+
+/// The level to bring all nails to and the hits that takes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Plan {
+    target: usize,
+    hits: usize,
+}
+
+/// Finds the target level that takes the fewest hits.
+///
+/// The hits are a convex, piecewise linear function of the target with
+/// kinks at the nail heights, so the best target is the first height at
+/// which raising it further stops paying off: the weighted quantile where
+/// `up * weight below <= down * weight above` flips. For equal costs in
+/// both directions that is the weighted median. Ties go to the lower level.
+fn solve(nails: &[Nail], cost: Cost) -> Result<Plan> {
+    let mut sorted = nails.to_vec();
+    sorted.sort_unstable_by_key(|nail| nail.height);
+    let (Some(lowest), Some(highest)) = (sorted.first(), sorted.last()) else {
+        return Err(QuestError::invalid("No nails"));
+    };
+
+    let target = match (cost.down, cost.up) {
+        (Some(_), None) => lowest.height,
+        (None, Some(_)) => highest.height,
+        (None, None) if lowest.height == highest.height => lowest.height,
+        (None, None) => return Err(QuestError::no_solution("Nails can't move")),
+        (Some(down), Some(up)) => {
+            let total: usize = sorted.iter().map(|nail| nail.weight).sum();
+            let mut below = 0;
+            sorted
+                .iter()
+                .find(|nail| {
+                    below += nail.weight;
+                    up * below >= down * (total - below)
+                })
+                .unwrap_or(highest)
+                .height
+        }
+    };
+    let hits = sorted
+        .iter()
+        .map(|nail| {
+            let per_level = if nail.height > target {
+                cost.down
+            } else {
+                cost.up
+            };
+            nail.weight * nail.height.abs_diff(target) * per_level.unwrap_or(0)
+        })
+        .sum();
+    Ok(Plan { target, hits })
+}
+
+/// Returns the nails of the input, all with weight 1, which are never empty.
+fn get_nails(input: &str) -> Result<Vec<Nail>> {
+    let nails = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.trim()
+                .parse()
+                .map(|height| Nail { height, weight: 1 })
+                .map_err(|err| QuestError::from(err).context(format!("nail {}", i + 1)))
+        })
+        .collect::<Result<Vec<Nail>>>()?;
+    if nails.is_empty() {
+        return Err(QuestError::invalid("No nails"));
+    }
+    Ok(nails)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1_example.txt");
+            let res = part1and2(&input);
+            assert_eq!(res, Ok(10));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part1.txt");
+            let res = part1and2(&input);
+            assert_eq!(res, Ok(84));
+        }
+
+        #[test]
+        fn invalid_nail() {
+            let res = part1and2("3\n4\nseven");
+            assert_eq!(
+                res,
+                Err(QuestError::Parse(
+                    "nail 3: invalid digit found in string".to_string()
+                ))
+            );
+        }
+    }
+
+    mod part2 {
+        use super::*;
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part2.txt");
+            let res = part1and2(&input);
+            assert_eq!(res, Ok(919880));
+        }
+    }
+
+    mod part3 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part3_example.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(8));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part3.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(129441494));
+        }
+
+        #[test]
+        fn target() {
+            let input = input::read_file("inputs/part3_example.txt");
+            let res = solve(&get_nails(&input).unwrap(), HAMMER_AND_PULL);
+            assert_eq!(res, Ok(Plan { target: 5, hits: 8 }));
+        }
+
+        #[test]
+        fn far_from_average() {
+            // The average of 0, 0 and 1000 is 333, the best target is 0.
+            let res = part3("0\n0\n1000");
+            assert_eq!(res, Ok(1000));
+        }
+    }
+
+    mod solve {
+        use super::*;
+
+        fn nails(nails: &[(usize, usize)]) -> Vec<Nail> {
+            nails
+                .iter()
+                .map(|&(height, weight)| Nail { height, weight })
+                .collect()
+        }
+
+        #[test]
+        fn weighted() {
+            let nails = nails(&[(1, 1), (4, 1), (10, 3)]);
+            let res = solve(&nails, HAMMER_AND_PULL);
+            assert_eq!(
+                res,
+                Ok(Plan {
+                    target: 10,
+                    hits: 15
+                })
+            );
+        }
+
+        #[test]
+        fn asymmetric() {
+            let nails = nails(&[(0, 1), (10, 1), (20, 1), (30, 1)]);
+            let pull_is_cheap = Cost::new(Some(3), Some(1));
+            let res = solve(&nails, pull_is_cheap);
+            assert_eq!(
+                res,
+                Ok(Plan {
+                    target: 20,
+                    hits: 60
+                })
+            );
+
+            let res = solve(&nails, Cost::new(None, Some(2)));
+            assert_eq!(
+                res,
+                Ok(Plan {
+                    target: 30,
+                    hits: 120
+                })
+            );
+        }
+
+        #[test]
+        fn immovable() {
+            let fixed = Cost::new(None, None);
+            let res = solve(&nails(&[(7, 1), (7, 2)]), fixed);
+            assert_eq!(res, Ok(Plan { target: 7, hits: 0 }));
+
+            let res = solve(&nails(&[(7, 1), (8, 1)]), fixed);
+            assert_eq!(res, Err(QuestError::no_solution("Nails can't move")));
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::{HashMap, VecDeque};
+use utils::cycle;
+use utils::error::{QuestError, Result};
+use utils::runner::Quest;
+
+fn main() {
+    Quest::new()
+        .part(1, part1)
+        .example("inputs/part1_example.txt")
+        .expect("2323")
+        .input("inputs/part1.txt")
+        .expect("2252")
+        .part(2, part2)
+        .example("inputs/part2and3_example.txt")
+        .expect(50877075)
+        .input("inputs/part2.txt")
+        .expect(21202068741084u64)
+        .part(3, part3)
+        .example("inputs/part2and3_example.txt")
+        .expect(6584)
+        .input("inputs/part3.txt")
+        .expect(4747374010031000u64)
+        .run();
+}
+
+fn part1(input: &str) -> Result<String> {
+    let mut columns = parse_columns(input)?;
+
+    for round in 1..=10 {
+        do_round(&mut columns, round)?;
+    }
+    Ok(columns.iter().map(|col| col[0].to_string()).collect())
+}
+
+/// Finds the first number shouted 2024 times and multiplies it by the round.
+///
+/// Small dances like the example repeat within a few rounds, and the answer
+/// follows from the repeating rounds. The real input does not repeat within
+/// 2^26 rounds, far beyond its answer, so there this is a plain simulation.
+fn part2(input: &str) -> Result<usize> {
+    const REPEATS: usize = 2024;
+    let columns = parse_columns(input)?;
+    let col_count = columns.len();
+    // The dance only depends on the columns and whose turn it is.
+    let mut state = (columns, 0);
+    let mut watch = cycle::Watch::new(state.clone());
+    let mut shouted: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for round in 1.. {
+        let number = do_round(&mut state.0, round)?;
+        state.1 = round % col_count;
+        let rounds = shouted.entry(number).or_default();
+        rounds.push(round);
+        if rounds.len() == REPEATS {
+            return Ok(number * round);
+        }
+        if let Some(period) = watch.push(&state) {
+            return repeat_shouts(&shouted, round, period, REPEATS);
+        }
+    }
+    unreachable!()
+}
+
+/// Finds the number that is shouted `repeats` times first, given the rounds
+/// in which each number was shouted so far, fewer than `repeats` times, once
+/// the dance repeats the last `period` rounds up to `round` forever.
+fn repeat_shouts(
+    shouted: &HashMap<usize, Vec<usize>>,
+    round: usize,
+    period: usize,
+    repeats: usize,
+) -> Result<usize> {
+    shouted
+        .iter()
+        .filter_map(|(number, rounds)| {
+            let lap_start = rounds.partition_point(|&r| r <= round - period);
+            let lap = &rounds[lap_start..];
+            // The shouts still missing, counted from 0, happen in the rounds
+            // of the last lap, shifted by one or more periods.
+            let missing = repeats - rounds.len() - 1;
+            let laps = missing.checked_div(lap.len())? + 1;
+            Some((lap[missing % lap.len()] + laps * period, *number))
+        })
+        .min()
+        .map(|(round, number)| number * round)
+        .ok_or_else(|| QuestError::no_solution(format!("No number is shouted {repeats} times")))
+}
+
+fn part3(input: &str) -> Result<usize> {
+    let columns = parse_columns(input)?;
+    let col_count = columns.len();
+
+    // The dance only depends on the columns and whose turn it is, so every
+    // number that will ever be shouted is seen before the first repetition.
+    // A failed round is a fixed point, so it ends the detection as well.
+    let history = cycle::detect(Ok((columns, 1)), |state: &Result<_>| {
+        state.clone().and_then(|(mut columns, round)| {
+            do_round(&mut columns, round)?;
+            Ok((columns, round % col_count + 1))
+        })
+    });
+    let mut max = 0;
+    for state in history.states().iter().skip(1) {
+        let (columns, _) = state.as_ref().map_err(Clone::clone)?;
+        max = max.max(shout(columns)?);
+    }
+    Ok(max)
+}
+
+fn do_round(columns: &mut [VecDeque<usize>], round: usize) -> Result<usize> {
+    let col_count = columns.len();
+    let old_col = (round - 1) % col_count;
+    let clapper = columns[old_col]
+        .pop_front()
+        .ok_or_else(|| QuestError::invalid(format!("Column {} ran empty", old_col + 1)))?;
+    let new_col = (old_col + 1) % col_count;
+
+    let pos = (clapper - 1) % (columns[new_col].len() * 2).max(1);
+    let index = if pos < columns[new_col].len() {
+        pos
+    } else {
+        columns[new_col].len() - (pos - columns[new_col].len())
+    };
+    columns[new_col].insert(index, clapper);
+
+    shout(columns)
+}
+
+fn shout(columns: &[VecDeque<usize>]) -> Result<usize> {
+    let mut number = String::new();
+    for (col, people) in columns.iter().enumerate() {
+        let front = people
+            .front()
+            .ok_or_else(|| QuestError::invalid(format!("Column {} ran empty", col + 1)))?;
+        number += &front.to_string();
+    }
+    number
+        .parse()
+        .map_err(|_| QuestError::invalid(format!("Shouted number {number} is too large")))
+}
+
+/// Returns the columns of dancers, all of them positive.
+fn parse_columns(input: &str) -> Result<Vec<VecDeque<usize>>> {
+    let col_count = input
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .count();
+    if col_count == 0 {
+        return Err(QuestError::invalid("No columns"));
+    }
+    let mut columns: Vec<VecDeque<usize>> = vec![VecDeque::new(); col_count];
+
+    for (row, line) in input.lines().enumerate() {
+        let numbers: Vec<&str> = line.split_whitespace().collect();
+        if numbers.len() != col_count {
+            return Err(QuestError::parse(format!(
+                "Row {} has {} numbers instead of {col_count}",
+                row + 1,
+                numbers.len()
+            )));
+        }
+        for (col, nbr) in numbers.into_iter().enumerate() {
+            match nbr.parse()? {
+                0 => return Err(QuestError::invalid("Dancer 0 cannot clap")),
+                nbr => columns[col].push_back(nbr),
+            }
+        }
+    }
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1_example.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok("2323".to_string()));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part1.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok("2252".to_string()));
+        }
+
+        #[test]
+        fn ragged_rows() {
+            let res = part1("2 3 4 5\n3 4 5\n4 5 2 3");
+            assert!(matches!(res, Err(QuestError::Parse(_))));
+        }
+    }
+
+    mod part2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part2and3_example.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(50877075));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part2.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(21202068741084));
+        }
+
+        #[test]
+        fn cycle() {
+            // Repeats every 14 rounds after round 9, so the answer comes
+            // from the cycle and not from simulating all 14173 rounds.
+            let res = part2("3 4\n5 6\n7 8");
+            assert_eq!(res, Ok(963764));
+        }
+
+        #[test]
+        fn repeat_shouts() {
+            // Rounds 3 to 6 repeat forever: 7 is shouted in rounds 4 and 6
+            // of every lap, 8 only in round 5, 9 never again.
+            let shouted = HashMap::from([(7, vec![2, 4, 6]), (8, vec![5]), (9, vec![1])]);
+            assert_eq!(super::repeat_shouts(&shouted, 6, 4, 4), Ok(7 * 8));
+            assert_eq!(super::repeat_shouts(&shouted, 6, 4, 6), Ok(7 * 12));
+
+            let shouted = HashMap::from([(7, vec![4]), (8, vec![1, 5])]);
+            assert_eq!(super::repeat_shouts(&shouted, 6, 4, 3), Ok(8 * 9));
+
+            let shouted = HashMap::from([(9, vec![1])]);
+            assert!(matches!(
+                super::repeat_shouts(&shouted, 6, 4, 3),
+                Err(QuestError::NoSolution(_))
+            ));
+        }
+    }
+
+    mod part3 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part2and3_example.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(6584));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part3.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(4747374010031000));
+        }
+    }
+}
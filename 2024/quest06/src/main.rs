@@ -1,30 +1,38 @@
 use std::collections::HashMap;
-use utils::input;
+use utils::error::{QuestError, Result};
+use utils::runner::Quest;
 
 fn main() {
-    let input = input::read_file("inputs/part1.txt");
-    println!("exercise 1: {}", part1(&input));
-    let input = input::read_file("inputs/part2.txt");
-    println!("exercise 2: {}", part2and3(&input));
-    let input = input::read_file("inputs/part3.txt");
-    println!("exercise 3: {}", part2and3(&input));
+    Quest::new()
+        .part(1, part1)
+        .example("inputs/part1_example.txt")
+        .expect("RRB@")
+        .input("inputs/part1.txt")
+        .expect("RRBSDGVPJHKG@")
+        .part(2, part2and3)
+        .input("inputs/part2.txt")
+        .expect("RFBMNWSHLW@")
+        .part(3, part2and3)
+        .input("inputs/part3.txt")
+        .expect("RPPLHWXLKSTB@")
+        .run();
 }
 
-fn part1(input: &str) -> String {
-    let tree = Tree::new(input);
-    path_to_string(&find_unique_path(&tree))
+fn part1(input: &str) -> Result<String> {
+    let tree = Tree::new(input)?;
+    Ok(path_to_string(&find_unique_path(&tree)?))
 }
 
-fn part2and3(input: &str) -> String {
-    let tree = Tree::new(input);
-    path_to_string_short(&find_unique_path(&tree))
+fn part2and3(input: &str) -> Result<String> {
+    let tree = Tree::new(input)?;
+    Ok(path_to_string_short(&find_unique_path(&tree)?))
 }
 
-fn find_unique_path<'a>(tree: &'a Tree<'a>) -> Vec<&'a Node<'a>> {
+fn find_unique_path<'a>(tree: &'a Tree<'a>) -> Result<Vec<&'a Node<'a>>> {
     let mut paths: HashMap</*length*/ usize, /*path*/ Option<Vec<&Node>>> = HashMap::new();
 
     for fruit in tree.fruits() {
-        let path: Vec<&Node> = tree.path(fruit);
+        let path: Vec<&Node> = tree.path(fruit)?;
         paths
             .entry(path.len())
             .and_modify(|e| *e = None)
@@ -34,7 +42,7 @@ fn find_unique_path<'a>(tree: &'a Tree<'a>) -> Vec<&'a Node<'a>> {
         .drain()
         .filter_map(|(_, v)| v)
         .next()
-        .expect("No unique path found")
+        .ok_or_else(|| QuestError::no_solution("No unique path found"))
 }
 
 fn path_to_string(path: &Vec<&Node>) -> String {
@@ -68,12 +76,14 @@ struct Tree<'a> {
 }
 
 impl<'input> Tree<'input> {
-    fn new(input: &'input str) -> Self {
+    fn new(input: &'input str) -> Result<Self> {
         let mut map = HashMap::new();
         let mut fruit_count = 0;
 
-        for line in input.lines() {
-            let (parent, children) = line.split_once(':').unwrap();
+        for (i, line) in input.lines().enumerate() {
+            let (parent, children) = line.split_once(':').ok_or_else(|| {
+                QuestError::parse(format!("Line {} has no ':' after the parent", i + 1))
+            })?;
 
             if parent == "BUG" || parent == "ANT" {
                 continue;
@@ -81,6 +91,12 @@ impl<'input> Tree<'input> {
             for child in children.split(',') {
                 let node: Node = match child {
                     "BUG" | "ANT" => continue,
+                    "" => {
+                        return Err(QuestError::parse(format!(
+                            "Line {} has an empty branch",
+                            i + 1
+                        )));
+                    }
                     "@" => {
                         let fruit_id = fruit_count;
                         fruit_count += 1;
@@ -91,29 +107,35 @@ impl<'input> Tree<'input> {
                 map.insert(node, Node::Branch(parent));
             }
         }
-        Tree { map }
+        Ok(Tree { map })
     }
 
-    fn fruits(&self) -> impl Iterator<Item = &Node> {
+    fn fruits(&self) -> impl Iterator<Item = &Node<'_>> {
         self.map
             .keys()
             .filter(|node| matches!(**node, Node::Fruit(_)))
     }
 
-    fn path(&'input self, mut node: &'input Node<'input>) -> Vec<&'input Node<'input>> {
+    fn path(&'input self, mut node: &'input Node<'input>) -> Result<Vec<&'input Node<'input>>> {
         let mut path: Vec<&'input Node> = vec![node];
 
         while let Some(parent) = self.map.get(node) {
+            if path.len() > self.map.len() {
+                return Err(QuestError::invalid(format!(
+                    "Branch {parent} is in a cycle"
+                )));
+            }
             node = parent;
             path.push(parent);
         }
-        path
+        Ok(path)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use utils::input;
 
     mod part1 {
         use super::*;
@@ -122,14 +144,23 @@ mod tests {
         fn example() {
             let input = input::read_file("inputs/part1_example.txt");
             let res = part1(&input);
-            assert_eq!(res, "RRB@");
+            assert_eq!(res, Ok("RRB@".to_string()));
         }
 
         #[test]
         fn answer() {
             let input = input::read_file("inputs/part1.txt");
             let res = part1(&input);
-            assert_eq!(res, "RRBSDGVPJHKG@");
+            assert_eq!(res, Ok("RRBSDGVPJHKG@".to_string()));
+        }
+
+        #[test]
+        fn no_unique_path() {
+            let res = part1("RR:A,B\nA:@\nB:@");
+            assert_eq!(
+                res,
+                Err(QuestError::NoSolution("No unique path found".to_string()))
+            );
         }
     }
 
@@ -140,7 +171,7 @@ mod tests {
         fn answer() {
             let input = input::read_file("inputs/part2.txt");
             let res = part2and3(&input);
-            assert_eq!(res, "RFBMNWSHLW@");
+            assert_eq!(res, Ok("RFBMNWSHLW@".to_string()));
         }
     }
 
@@ -151,7 +182,7 @@ mod tests {
         fn answer() {
             let input = input::read_file("inputs/part3.txt");
             let res = part2and3(&input);
-            assert_eq!(res, "RPPLHWXLKSTB@");
+            assert_eq!(res, Ok("RPPLHWXLKSTB@".to_string()));
         }
     }
 }
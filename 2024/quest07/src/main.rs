@@ -0,0 +1,285 @@
+use itertools::Itertools;
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+use utils::error::{QuestError, Result};
+use utils::input;
+use utils::parse::note::{FromNote, Parser, Span, symbol};
+use utils::runner::Quest;
+
+fn main() {
+    Quest::new()
+        .part(1, part1)
+        .example("inputs/part1and2_example.txt")
+        .expect("BDCA")
+        .input("inputs/part1.txt")
+        .expect("BCGDKIHAE")
+        .part(2, |input| {
+            part2(
+                input,
+                &input::try_read_file("inputs/part2_track_example.txt")?,
+            )
+        })
+        .example("inputs/part1and2_example.txt")
+        .expect("DCBA")
+        .part(2, |input| {
+            part2(input, &input::try_read_file("inputs/part2_track.txt")?)
+        })
+        .input("inputs/part2.txt")
+        .expect("FAIKHBEJG")
+        .part(3, |input| {
+            part3(input, &input::try_read_file("inputs/part3_track.txt")?)
+        })
+        .input("inputs/part3.txt")
+        .expect(5839)
+        .run();
+}
+
+fn part1(input: &str) -> Result<String> {
+    let mut plans: Vec<Plan> = input.lines().map(Plan::new).collect::<Result<_>>()?;
+
+    plans.sort_by_cached_key(|plan| plan.value(10, 10));
+    Ok(plans.iter().rev().map(|plan| plan.id).collect())
+}
+
+fn part2(input: &str, track: &str) -> Result<String> {
+    let mut plans: Vec<Plan> = input.lines().map(Plan::new).collect::<Result<_>>()?;
+    let track_actions: Vec<Action> = track::parse_track_actions(track)?;
+
+    plans.sort_by_cached_key(|plan| plan.value_on_track(10, &track_actions, 10));
+    Ok(plans.iter().rev().map(|plan| plan.id).collect())
+}
+
+fn part3(input: &str, track: &str) -> Result<usize> {
+    let track_actions: Vec<Action> = track::parse_track_actions(track)?;
+    let plan_to_beat = Plan::new(input)?;
+    let score_to_beat = plan_to_beat.value_on_track(10, &track_actions, 2024);
+
+    Ok(Plan::permutations(5, 3, 3)
+        .filter(|plan| plan.value_on_track(10, &track_actions, 2024) > score_to_beat)
+        .count())
+}
+
+#[derive(Debug, FromNote)]
+#[note(delim = ":")]
+struct Plan {
+    #[note(part = 1, sep = ",")]
+    actions: Vec<Action>,
+    #[note(part = 0)]
+    id: char,
+}
+
+impl Plan {
+    /// Parses a plan, which has at least one action.
+    fn new(line: &str) -> Result<Self> {
+        let plan = Plan::from_note(line)
+            .map_err(|err| QuestError::from(err).context(format!("plan {line:?}")))?;
+        if plan.actions.is_empty() {
+            return Err(QuestError::invalid(format!(
+                "Plan {} has no actions",
+                plan.id
+            )));
+        }
+        Ok(plan)
+    }
+
+    fn permutations(pluses: usize, minuses: usize, equals: usize) -> impl Iterator<Item = Self> {
+        vec![Action::Plus; pluses]
+            .into_iter()
+            .chain(vec![Action::Minus; minuses])
+            .chain(vec![Action::Equal; equals])
+            .permutations(pluses + minuses + equals)
+            .sorted_unstable()
+            .dedup()
+            .enumerate()
+            .map(|(n, actions)| Self {
+                actions,
+                id: (b'A' + (n % 26) as u8) as char,
+            })
+    }
+
+    fn value(&self, mut power: usize, length: usize) -> usize {
+        let mut value = 0;
+
+        for mut i in 0..length {
+            i %= self.actions.len();
+            power += self.actions[i];
+            value += power;
+        }
+        value
+    }
+
+    fn value_on_track(&self, mut power: usize, track_actions: &[Action], loops: usize) -> usize {
+        let mut value = 0;
+
+        for (i, track_action) in track_actions
+            .iter()
+            .cycle()
+            .take(track_actions.len() * loops)
+            .enumerate()
+        {
+            power += if matches!(track_action, Action::Equal) {
+                self.actions[i % self.actions.len()]
+            } else {
+                *track_action
+            };
+            value += power;
+        }
+        value
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Action {
+    Plus,
+    Minus,
+    Equal,
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    /// Parses a single symbol the same way as on a track.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let symbol = symbol()
+            .parse(Span::new(s))
+            .map_err(|_| format!("Invalid symbol for Action: {s}"))?;
+        Action::try_from(symbol)
+    }
+}
+
+impl TryFrom<char> for Action {
+    type Error = String;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '+' => Ok(Action::Plus),
+            '-' => Ok(Action::Minus),
+            '=' | 'S' => Ok(Action::Equal),
+            _ => Err(format!("Invalid symbol for Action: {value}")),
+        }
+    }
+}
+
+impl Add<Action> for usize {
+    type Output = usize;
+
+    fn add(self, rhs: Action) -> Self::Output {
+        match rhs {
+            Action::Plus => self + 1,
+            Action::Minus => self.saturating_sub(1),
+            Action::Equal => self,
+        }
+    }
+}
+
+impl AddAssign<Action> for usize {
+    fn add_assign(&mut self, rhs: Action) {
+        *self = *self + rhs;
+    }
+}
+
+mod track {
+    use crate::Action;
+    use utils::error::{QuestError, Result};
+    use utils::grid::{self, Grid};
+
+    pub fn parse_track_actions(track: &str) -> Result<Vec<Action>> {
+        if track.lines().next().is_none() {
+            return Err(QuestError::parse("Empty track"));
+        }
+        let track2d = Grid::from(track);
+        let start = track2d
+            .iter()
+            .find_map(|(p, &c)| (c == 'S').then_some(p))
+            .ok_or_else(|| QuestError::parse("No starting point 'S' found"))?;
+        let mut track_positions = grid::trace_loop(&track2d, start, |c| !c.is_whitespace())
+            .map_err(|err| QuestError::from(err).context("track"))?;
+
+        // Actions start after 'S' and end with 'S'
+        track_positions.rotate_left(1);
+        track_positions
+            .iter()
+            .map(|pos| {
+                let c = *track2d.get(pos).unwrap();
+                Action::try_from(c).map_err(QuestError::parse)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1and2_example.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok("BDCA".to_string()));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part1.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok("BCGDKIHAE".to_string()));
+        }
+
+        #[test]
+        fn plan_symbols() {
+            let plan = Plan::new("A:+,-,=,S").unwrap();
+            let actions = [Action::Plus, Action::Minus, Action::Equal, Action::Equal];
+            assert_eq!(plan.actions, actions);
+            assert!("+-".parse::<Action>().is_err());
+        }
+
+        #[test]
+        fn invalid_action() {
+            let res = part1("A:+,-,=,=\nB:+,*,=,-");
+            let msg = r#"plan "B:+,*,=,-": line 1, column 5: invalid value `*`"#;
+            assert_eq!(res, Err(QuestError::Parse(msg.to_string())));
+        }
+    }
+
+    mod part2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1and2_example.txt");
+            let track = input::read_file("inputs/part2_track_example.txt");
+            let res = part2(&input, &track);
+            assert_eq!(res, Ok("DCBA".to_string()));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part2.txt");
+            let track = input::read_file("inputs/part2_track.txt");
+            let res = part2(&input, &track);
+            assert_eq!(res, Ok("FAIKHBEJG".to_string()));
+        }
+
+        #[test]
+        fn empty_track() {
+            let input = input::read_file("inputs/part1and2_example.txt");
+            let res = part2(&input, "");
+            assert_eq!(res, Err(QuestError::parse("Empty track")));
+        }
+    }
+
+    mod part3 {
+        use super::*;
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part3.txt");
+            let track = input::read_file("inputs/part3_track.txt");
+            let res = part3(&input, &track);
+            assert_eq!(res, Ok(5839));
+        }
+    }
+}
@@ -1,16 +1,30 @@
-use utils::input;
+use utils::error::{QuestError, Result};
+use utils::runner::Quest;
 
 fn main() {
-    let input = input::read_file("inputs/part1.txt");
-    println!("exercise 1: {}", part1(&input));
-    let input = input::read_file("inputs/part2.txt");
-    println!("exercise 2: {}", part2(&input, 1111, 20240000));
-    let input = input::read_file("inputs/part3.txt");
-    println!("exercise 3: {}", part3(&input, 10, 202400000));
+    Quest::new()
+        .part(1, part1)
+        .example("inputs/part1_example.txt")
+        .expect(21)
+        .input("inputs/part1.txt")
+        .expect(7822668)
+        .part(2, |input| part2(input, 5, 50))
+        .example("inputs/part2_example.txt")
+        .expect(27)
+        .part(2, |input| part2(input, 1111, 20240000))
+        .input("inputs/part2.txt")
+        .expect(133388862)
+        .part(3, |input| part3(input, 5, 160))
+        .example("inputs/part3_example.txt")
+        .expect(2)
+        .part(3, |input| part3(input, 10, 202400000))
+        .input("inputs/part3.txt")
+        .expect(41067)
+        .run();
 }
 
-fn part1(input: &str) -> usize {
-    let available: usize = input.parse().unwrap();
+fn part1(input: &str) -> Result<usize> {
+    let available: usize = input.trim().parse()?;
     let mut blocks = 1;
     let mut width = 1;
 
@@ -18,25 +32,28 @@ fn part1(input: &str) -> usize {
         width += 2;
         blocks += width;
     }
-    (blocks - available) * width
+    Ok((blocks - available) * width)
 }
 
-fn part2(input: &str, acolytes: usize, available: usize) -> usize {
-    let priests: usize = input.parse().unwrap();
+fn part2(input: &str, acolytes: usize, available: usize) -> Result<usize> {
+    let priests: usize = input.trim().parse()?;
     let mut blocks = 1;
     let mut width = 1;
     let mut thickness = 1;
 
     while blocks < available {
         thickness = (thickness * priests) % acolytes;
+        if thickness == 0 {
+            return Err(QuestError::no_solution("The shrine stops growing"));
+        }
         width += 2;
         blocks += width * thickness;
     }
-    (blocks - available) * width
+    Ok((blocks - available) * width)
 }
 
-fn part3(input: &str, acolytes: usize, available: usize) -> usize {
-    let priests: usize = input.parse().unwrap();
+fn part3(input: &str, acolytes: usize, available: usize) -> Result<usize> {
+    let priests: usize = input.trim().parse()?;
     let mut shrine = Shrine::new();
 
     while shrine.blocks < available {
@@ -51,7 +68,7 @@ fn part3(input: &str, acolytes: usize, available: usize) -> usize {
         }
         shrine.add_layer(priests, acolytes);
     }
-    shrine.blocks - empty_blocks - available
+    Ok(shrine.blocks - empty_blocks - available)
 }
 
 struct Shrine {
@@ -95,6 +112,7 @@ impl Shrine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use utils::input;
 
     mod part1 {
         use super::*;
@@ -103,14 +121,14 @@ mod tests {
         fn example() {
             let input = input::read_file("inputs/part1_example.txt");
             let res = part1(&input);
-            assert_eq!(res, 21);
+            assert_eq!(res, Ok(21));
         }
 
         #[test]
         fn answer() {
             let input = input::read_file("inputs/part1.txt");
             let res = part1(&input);
-            assert_eq!(res, 7822668);
+            assert_eq!(res, Ok(7822668));
         }
     }
 
@@ -121,14 +139,20 @@ mod tests {
         fn example() {
             let input = input::read_file("inputs/part2_example.txt");
             let res = part2(&input, 5, 50);
-            assert_eq!(res, 27);
+            assert_eq!(res, Ok(27));
         }
 
         #[test]
         fn answer() {
             let input = input::read_file("inputs/part2.txt");
             let res = part2(&input, 1111, 20240000);
-            assert_eq!(res, 133388862);
+            assert_eq!(res, Ok(133388862));
+        }
+
+        #[test]
+        fn stops_growing() {
+            let res = part2("10", 5, 50);
+            assert!(matches!(res, Err(QuestError::NoSolution(_))));
         }
     }
 
@@ -139,21 +163,21 @@ mod tests {
         fn example() {
             let input = input::read_file("inputs/part3_example.txt");
             let res = part3(&input, 5, 160);
-            assert_eq!(res, 2);
+            assert_eq!(res, Ok(2));
         }
 
         #[test]
         fn answer() {
             let input = input::read_file("inputs/part3.txt");
             let res = part3(&input, 10, 202400000);
-            assert_eq!(res, 41067);
+            assert_eq!(res, Ok(41067));
         }
 
         #[test]
         fn answer_pre_update() {
             let input = input::read_file("inputs/part3.txt");
             let res = part3(&input, 10, 202400000000);
-            assert_eq!(res, 204163);
+            assert_eq!(res, Ok(204163));
         }
     }
 }
@@ -0,0 +1,134 @@
+use utils::change::Change;
+use utils::error::{QuestError, Result};
+use utils::parse;
+use utils::runner::Quest;
+
+fn main() {
+    Quest::new()
+        .part(1, part1)
+        .example("inputs/part1_example.txt")
+        .expect(10)
+        .input("inputs/part1.txt")
+        .expect(12218)
+        .part(2, part2)
+        .example("inputs/part2_example.txt")
+        .expect(10)
+        .input("inputs/part2.txt")
+        .expect(5057)
+        .part(3, part3)
+        .example("inputs/part3_example.txt")
+        .expect(10449)
+        .input("inputs/part3.txt")
+        .expect(148836)
+        .run();
+}
+
+// Greedy
+fn part1(input: &str) -> Result<usize> {
+    let change = Change::new(&[1, 3, 5, 10]);
+
+    parse::try_numbers(input)
+        .map(|target| {
+            let target = target?;
+            change.greedy(target).ok_or_else(|| no_solution(target))
+        })
+        .sum()
+}
+
+// Dynamic programming
+fn part2(input: &str) -> Result<usize> {
+    let mut change = Change::new(&[1, 3, 5, 10, 15, 16, 20, 24, 25, 30]);
+
+    parse::try_numbers(input)
+        .map(|target| {
+            let target = target?;
+            change.min_count(target).ok_or_else(|| no_solution(target))
+        })
+        .sum()
+}
+
+fn part3(input: &str) -> Result<usize> {
+    let mut change = Change::new(&[
+        1, 3, 5, 10, 15, 16, 20, 24, 25, 30, 37, 38, 49, 50, 74, 75, 100, 101,
+    ]);
+    let max_diff = 100;
+
+    parse::try_numbers(input)
+        .map(|target| {
+            let target = target?;
+            change
+                .min_count_pair(target, max_diff)
+                .ok_or_else(|| no_solution(target))
+        })
+        .sum()
+}
+
+fn no_solution(target: usize) -> QuestError {
+    QuestError::no_solution(format!("No combination of stamps for {target}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1_example.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok(10));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part1.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok(12218));
+        }
+
+        #[test]
+        fn too_large() {
+            let res = part1("2\n99999999999999999999999\n4");
+            assert!(matches!(res, Err(QuestError::Parse(_))));
+        }
+    }
+
+    mod part2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part2_example.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(10));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part2.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(5057));
+        }
+    }
+
+    mod part3 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part3_example.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(10449));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part3.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(148836));
+        }
+    }
+}
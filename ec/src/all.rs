@@ -0,0 +1,162 @@
+use crate::workspace;
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use utils::colors::{BOLD, GREEN, RED, RESET, YELLOW};
+use utils::event::QuestId;
+use utils::runner::{Report, Status};
+
+const PARTS: [u8; 3] = [1, 2, 3];
+
+/// Runs every part of every quest in parallel and prints a summary table.
+///
+/// Returns an error if any part failed or gave a wrong answer.
+pub fn run(root: &Path) -> Result<(), String> {
+    let quests = workspace::quests(root);
+    workspace::build(root, &quests)?;
+
+    let start = Instant::now();
+    let jobs: Vec<(QuestId, u8)> = quests
+        .iter()
+        .flat_map(|&id| PARTS.map(|part| (id, part)))
+        .collect();
+    let mut rows = run_jobs(root, &jobs);
+    rows.sort_by_key(|(id, report)| (*id, report.part, !report.example));
+
+    print_table(&rows);
+    let failed = rows.iter().filter(|(_, report)| report.failed()).count();
+    let summary = format!(
+        "{} runs, {} failed in {:.2?}",
+        rows.len(),
+        failed,
+        start.elapsed()
+    );
+    match failed {
+        0 => {
+            println!("{BOLD}{GREEN}{summary}{RESET}");
+            Ok(())
+        }
+        _ => Err(format!("{BOLD}{RED}{summary}{RESET}")),
+    }
+}
+
+/// Runs the jobs on a pool of one thread per core.
+fn run_jobs(root: &Path, jobs: &[(QuestId, u8)]) -> Vec<(QuestId, Report)> {
+    let next = AtomicUsize::new(0);
+    let rows = Mutex::new(Vec::new());
+    let workers = thread::available_parallelism().map_or(1, |n| n.get());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.min(jobs.len()) {
+            scope.spawn(|| {
+                while let Some(&(id, part)) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let reports = run_part(root, id, part);
+                    let mut rows = rows.lock().unwrap();
+                    rows.extend(reports.into_iter().map(|report| (id, report)));
+                }
+            });
+        }
+    });
+    rows.into_inner().unwrap()
+}
+
+/// Runs one part of a quest binary in its own process, so a crash cannot
+/// take down the other parts.
+fn run_part(root: &Path, id: QuestId, part: u8) -> Vec<Report> {
+    let start = Instant::now();
+    let output = Command::new(workspace::binary(id))
+        .args(["--report", "--part", &part.to_string()])
+        .current_dir(root)
+        .output();
+    let crash = |message: String| Report {
+        part,
+        example: false,
+        status: Status::Panic,
+        answer: message,
+        expected: None,
+        time: start.elapsed(),
+    };
+
+    match output {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let reports: Vec<Report> = stdout.lines().filter_map(Report::from_line).collect();
+            if reports.is_empty() && !output.status.success() {
+                vec![crash(format!("exited with {}", output.status))]
+            } else {
+                reports
+            }
+        }
+        Err(err) => vec![crash(format!("failed to start: {err}"))],
+    }
+}
+
+fn print_table(rows: &[(QuestId, Report)]) {
+    let header = ["Quest", "Part", "Input", "Answer", "Time", "Status"];
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|(id, report)| {
+            [
+                id.to_string(),
+                report.part.to_string(),
+                if report.example { "example" } else { "input" }.to_string(),
+                report.answer.clone(),
+                format_time(report.time),
+                report.status.name().to_string(),
+            ]
+        })
+        .collect();
+    let mut widths = header.map(str::len);
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |row: &[String; 6], color: &str| {
+        let [quest, part, input, answer, time, status] = row;
+        println!(
+            "{quest:<w0$}  {part:>w1$}  {input:<w2$}  {answer:<w3$}  {time:>w4$}  {color}{status}{RESET}",
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+    };
+    line(&header.map(str::to_string), BOLD);
+    for ((_, report), row) in rows.iter().zip(&cells) {
+        line(row, status_color(report.status));
+    }
+}
+
+fn status_color(status: Status) -> &'static str {
+    match status {
+        Status::Ok => GREEN,
+        Status::Unchecked => YELLOW,
+        Status::Wrong | Status::Error | Status::Panic => RED,
+    }
+}
+
+fn format_time(time: Duration) -> String {
+    match time.as_millis() {
+        0 => format!("{}µs", time.as_micros()),
+        ms if ms < 10_000 => format!("{ms}ms"),
+        _ => format!("{:.1}s", time.as_secs_f64()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time() {
+        assert_eq!(format_time(Duration::from_micros(420)), "420µs");
+        assert_eq!(format_time(Duration::from_micros(4200)), "4ms");
+        assert_eq!(format_time(Duration::from_millis(52_340)), "52.3s");
+    }
+}
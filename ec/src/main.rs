@@ -0,0 +1,48 @@
+mod all;
+mod new;
+mod watch;
+mod workspace;
+
+use std::env;
+use std::process;
+use utils::event::{Event, QuestId};
+
+const USAGE: &str = "\
+Usage: ec <command>
+
+Commands:
+  all                Run all parts of all quests and print a summary
+  new <EVENT> <N>    Create <EVENT>/questNN and add it to the workspace
+  watch <EVENT> <N>  Rerun <EVENT>/questNN whenever its files change
+
+Events are years like 2024 or stories like story1.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let res = match args[..] {
+        ["all"] => all::run(&workspace::root()),
+        ["new", event, quest] => parse_quest_id(event, quest).and_then(|id| {
+            let dir = new::new_quest(&workspace::root(), id)?;
+            println!("Created {}", dir.display());
+            Ok(())
+        }),
+        ["watch", event, quest] => {
+            parse_quest_id(event, quest).and_then(|id| watch::run(&workspace::root(), id))
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(err) = res {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
+
+fn parse_quest_id(event: &str, quest: &str) -> Result<QuestId, String> {
+    let event: Event = event.parse().map_err(|err| format!("{err}"))?;
+    match quest.parse() {
+        Ok(quest) if quest > 0 => Ok(QuestId::new(event, quest)),
+        _ => Err(format!("Invalid quest number: {quest}")),
+    }
+}
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use utils::event::QuestId;
+
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs");
+
+/// Creates the crate for `id` in `root` and adds it to the workspace members.
+pub fn new_quest(root: &Path, id: QuestId) -> Result<PathBuf, String> {
+    let member = id.dir().to_string_lossy().replace('\\', "/");
+    let dir = root.join(id.dir());
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path).map_err(|err| err.to_string())?;
+    let manifest = add_member(&manifest, &member)?;
+
+    let write = |path: PathBuf, contents: &str| {
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, contents))
+            .map_err(|err| format!("Failed to write {}: {err}", path.display()))
+    };
+    write(dir.join("Cargo.toml"), &cargo_toml(&id.package()))?;
+    write(dir.join("src/main.rs"), MAIN_TEMPLATE)?;
+    for part in 1..=3 {
+        write(dir.join(format!("inputs/part{part}.txt")), "")?;
+        write(dir.join(format!("inputs/part{part}_example.txt")), "")?;
+    }
+    write(manifest_path, &manifest)?;
+    Ok(dir)
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        "\
+[package]
+name = \"{name}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies]
+utils.workspace = true
+"
+    )
+}
+
+/// Returns the workspace manifest with `member` added to the sorted
+/// `members` list.
+fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("No workspace members found in Cargo.toml")?
+        + "members = [".len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("Unterminated workspace members in Cargo.toml")?;
+
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&member) {
+        return Err(format!("{member} is already a workspace member"));
+    }
+    members.push(member);
+    members.sort_unstable();
+
+    let members: Vec<String> = members.iter().map(|m| format!("\"{m}\"")).collect();
+    Ok(format!(
+        "{} {} {}",
+        &manifest[..start],
+        members.join(", "),
+        &manifest[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use utils::event::Event;
+
+    #[test]
+    fn add_member_sorted() {
+        let manifest = "[workspace]\nmembers = [ \"quest01\", \"quest03\",\"utils\"]\n";
+        let res = add_member(manifest, "quest02");
+        assert_eq!(
+            res,
+            Ok(
+                "[workspace]\nmembers = [ \"quest01\", \"quest02\", \"quest03\", \"utils\" ]\n"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn add_member_multiline() {
+        let manifest = "members = [\n    \"quest01\",\n    \"utils\",\n]\n";
+        let res = add_member(manifest, "quest10");
+        assert_eq!(
+            res,
+            Ok("members = [ \"quest01\", \"quest10\", \"utils\" ]\n".to_string())
+        );
+    }
+
+    #[test]
+    fn add_member_existing() {
+        let manifest = "members = [ \"quest01\" ]";
+        assert!(add_member(manifest, "quest01").is_err());
+    }
+
+    #[test]
+    fn new_quest_files() {
+        let root = env::temp_dir().join(format!("ec_new_quest_{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [ \"utils\" ]\n",
+        )
+        .unwrap();
+
+        let id = QuestId::new(Event::Story(1), 10);
+        let dir = new_quest(&root, id).unwrap();
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let quest_manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        let inputs = fs::read_dir(dir.join("inputs")).unwrap().count();
+        let again = new_quest(&root, id);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(dir, root.join("story1/quest10"));
+        assert!(manifest.contains("[ \"story1/quest10\", \"utils\" ]"));
+        assert!(quest_manifest.contains("name = \"story1_quest10\""));
+        assert!(quest_manifest.contains("utils.workspace = true"));
+        assert_eq!(inputs, 6);
+        assert!(again.is_err());
+    }
+}
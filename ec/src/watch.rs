@@ -0,0 +1,102 @@
+use crate::workspace;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use utils::colors::{BOLD, RED, RESET};
+use utils::event::QuestId;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Rebuilds and reruns the quest whenever a file of it or of `utils`
+/// changes. Changes are found by polling modification times.
+pub fn run(root: &Path, id: QuestId) -> Result<(), String> {
+    let dir = root.join(id.dir());
+    if !dir.is_dir() {
+        return Err(format!("{} does not exist", dir.display()));
+    }
+    let watched = [dir, root.join("utils")];
+    let mut last = Snapshot::new();
+
+    loop {
+        let current = snapshot(&watched);
+        if current != last {
+            last = current;
+            rerun(root, id);
+            println!("\nWatching {} for changes...", id.dir().display());
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn rerun(root: &Path, id: QuestId) {
+    println!("{BOLD}=== {id} ==={RESET}");
+    if let Err(err) = workspace::build(root, &[id]) {
+        println!("{RED}{err}{RESET}");
+        return;
+    }
+    // The runner runs the examples first and checks the expected answers.
+    if let Err(err) = Command::new(workspace::binary(id))
+        .current_dir(root)
+        .status()
+    {
+        println!("{RED}Failed to run {}: {err}{RESET}", id.package());
+    }
+}
+
+/// Returns the modification times of all files below `dirs`, skipping
+/// `target` directories.
+fn snapshot(dirs: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut stack: Vec<PathBuf> = dirs.to_vec();
+
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                if entry.file_name() != "target" {
+                    stack.push(path);
+                }
+            } else if let Ok(modified) = metadata.modified() {
+                snapshot.insert(path, modified);
+            }
+        }
+    }
+    snapshot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn snapshot_changes() {
+        let dir = env::temp_dir().join(format!("ec_watch_{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        fs::write(dir.join("src/main.rs"), "fn main() {}").unwrap();
+
+        let dirs = [dir.clone()];
+        let first = snapshot(&dirs);
+        fs::write(dir.join("target/quest"), "").unwrap();
+        let ignored = snapshot(&dirs);
+        fs::write(dir.join("inputs.txt"), "1").unwrap();
+        let changed = snapshot(&dirs);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(first, ignored);
+        assert_ne!(first, changed);
+    }
+}
@@ -0,0 +1,89 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use utils::event::{Event, QuestId};
+
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("ec is not inside the workspace")
+        .to_path_buf()
+}
+
+/// Returns all quests of all events in `root`, sorted.
+pub fn quests(root: &Path) -> Vec<QuestId> {
+    let mut quests = Vec::new();
+
+    for (event, dir) in subdirs(root) {
+        let Ok(event) = event.parse::<Event>() else {
+            continue;
+        };
+        for (quest, _) in subdirs(&dir) {
+            let quest = quest.strip_prefix("quest").and_then(|q| q.parse().ok());
+            if let Some(quest) = quest.filter(|&q| q > 0) {
+                quests.push(QuestId::new(event, quest));
+            }
+        }
+    }
+    quests.sort();
+    quests
+}
+
+fn subdirs(dir: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| Some((entry.file_name().into_string().ok()?, entry.path())))
+        .collect()
+}
+
+/// Builds the quest binaries with the profile `ec` itself was built with.
+pub fn build(root: &Path, packages: &[QuestId]) -> Result<(), String> {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    cargo.arg("build").arg("--bins").current_dir(root);
+    if profile_dir()
+        .file_name()
+        .is_some_and(|name| name == "release")
+    {
+        cargo.arg("--release");
+    }
+    for id in packages {
+        cargo.arg("-p").arg(id.package());
+    }
+
+    let status = cargo
+        .status()
+        .map_err(|err| format!("Failed to run cargo: {err}"))?;
+    match status.success() {
+        true => Ok(()),
+        false => Err(format!("cargo build failed with {status}")),
+    }
+}
+
+/// Returns the path of the binary of `id`, next to the `ec` binary.
+pub fn binary(id: QuestId) -> PathBuf {
+    profile_dir().join(format!("{}{}", id.package(), env::consts::EXE_SUFFIX))
+}
+
+fn profile_dir() -> PathBuf {
+    env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quests_in_workspace() {
+        let quests = quests(&root());
+        assert!(quests.contains(&QuestId::new(Event::Year(2024), 1)));
+        assert!(quests.windows(2).all(|w| w[0] < w[1]));
+    }
+}
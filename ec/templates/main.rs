@@ -0,0 +1,88 @@
+use utils::error::Result;
+use utils::runner::Quest;
+
+fn main() {
+    Quest::new()
+        .part(1, part1)
+        .example("inputs/part1_example.txt")
+        .input("inputs/part1.txt")
+        .part(2, part2)
+        .example("inputs/part2_example.txt")
+        .input("inputs/part2.txt")
+        .part(3, part3)
+        .example("inputs/part3_example.txt")
+        .input("inputs/part3.txt")
+        .run();
+}
+
+fn part1(_input: &str) -> Result<usize> {
+    todo!()
+}
+
+fn part2(_input: &str) -> Result<usize> {
+    todo!()
+}
+
+fn part3(_input: &str) -> Result<usize> {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part1_example.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok(0));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part1.txt");
+            let res = part1(&input);
+            assert_eq!(res, Ok(0));
+        }
+    }
+
+    mod part2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part2_example.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(0));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part2.txt");
+            let res = part2(&input);
+            assert_eq!(res, Ok(0));
+        }
+    }
+
+    mod part3 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("inputs/part3_example.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(0));
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("inputs/part3.txt");
+            let res = part3(&input);
+            assert_eq!(res, Ok(0));
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitInt, LitStr};
+
+/// Derives `utils::parse::note::FromNote` for a struct with named fields.
+///
+/// The struct may set `#[note(delim = "...")]` to split the note into parts.
+/// Each field picks its text with at most one of
+///
+/// - `prefix = "WORDS:"`: the rest of the first line starting with the prefix,
+/// - `section = 1`: the block with this index, blocks being separated by
+///   blank lines,
+/// - `part = 1`: the part with this index after splitting at `delim`,
+///
+/// and converts it with at most one of
+///
+/// - `sep = ","`: a `Vec` of items separated by this char,
+/// - `words`: a `Vec` of whitespace separated items,
+/// - `lines`: a `Vec` with one item per line,
+/// - `grid`: a `Grid<char>`.
+///
+/// Without a source the whole note is used, without a conversion the trimmed
+/// text is parsed with `FromStr`.
+#[proc_macro_derive(FromNote, attributes(note))]
+pub fn derive_from_note(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+enum Source {
+    Whole,
+    Prefix(LitStr),
+    Section(LitInt),
+    Part(LitInt),
+}
+
+enum Conversion {
+    Value,
+    Sep(char),
+    Words,
+    Lines,
+    Grid,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "FromNote needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "FromNote can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut delim: Option<LitStr> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("note")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("delim") {
+                delim = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown struct attribute, expected `delim`"))
+            }
+        })?;
+    }
+
+    let mut idents = Vec::new();
+    let mut inits = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let (source, conversion) = field_options(field)?;
+
+        let conversion = match conversion {
+            Conversion::Value => quote!(note::value()),
+            Conversion::Sep(sep) => quote!(note::list(#sep, note::value())),
+            Conversion::Words => quote!(note::words(note::value())),
+            Conversion::Lines => quote!(note::lines(note::value())),
+            Conversion::Grid => quote!(note::grid()),
+        };
+        let parser = match source {
+            Source::Whole => conversion,
+            Source::Prefix(prefix) => quote!(note::find_prefixed(#prefix, #conversion)),
+            Source::Section(index) => quote!(note::section(#index, #conversion)),
+            Source::Part(index) => match &delim {
+                Some(delim) => quote!(note::part(#delim, #index, #conversion)),
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        index,
+                        "`part` needs `#[note(delim = \"...\")]` on the struct",
+                    ))
+                }
+            },
+        };
+        inits.push(quote!(let #ident = note::Parser::parse(&#parser, __note_span)?;));
+        idents.push(ident);
+    }
+
+    Ok(quote! {
+        impl ::utils::parse::note::FromNote for #name {
+            fn from_note(__note_input: &str) -> ::utils::parse::note::Result<Self> {
+                use ::utils::parse::note;
+
+                // Prefixed so they can't collide with the field names.
+                let __note_text = ::utils::input::normalize(__note_input);
+                let __note_span = note::Span::new(&__note_text);
+                #(#inits)*
+                Ok(Self { #(#idents),* })
+            }
+        }
+    })
+}
+
+fn field_options(field: &syn::Field) -> syn::Result<(Source, Conversion)> {
+    let mut source = Source::Whole;
+    let mut conversion = Conversion::Value;
+    let mut has_source = false;
+    let mut has_conversion = false;
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("note")) {
+        attr.parse_nested_meta(|meta| {
+            let is_source = ["prefix", "section", "part"]
+                .iter()
+                .any(|key| meta.path.is_ident(key));
+            if is_source && std::mem::replace(&mut has_source, true) {
+                return Err(meta.error("only one of `prefix`, `section` and `part` is allowed"));
+            }
+            let is_conversion = ["sep", "words", "lines", "grid"]
+                .iter()
+                .any(|key| meta.path.is_ident(key));
+            if is_conversion && std::mem::replace(&mut has_conversion, true) {
+                return Err(meta.error("only one of `sep`, `words`, `lines` and `grid` is allowed"));
+            }
+
+            if meta.path.is_ident("prefix") {
+                source = Source::Prefix(meta.value()?.parse()?);
+            } else if meta.path.is_ident("section") {
+                source = Source::Section(meta.value()?.parse()?);
+            } else if meta.path.is_ident("part") {
+                source = Source::Part(meta.value()?.parse()?);
+            } else if meta.path.is_ident("sep") {
+                let sep: LitStr = meta.value()?.parse()?;
+                let value = sep.value();
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => conversion = Conversion::Sep(c),
+                    _ => return Err(syn::Error::new_spanned(sep, "`sep` must be a single char")),
+                }
+            } else if meta.path.is_ident("words") {
+                conversion = Conversion::Words;
+            } else if meta.path.is_ident("lines") {
+                conversion = Conversion::Lines;
+            } else if meta.path.is_ident("grid") {
+                conversion = Conversion::Grid;
+            } else {
+                return Err(meta.error(
+                    "unknown field attribute, expected one of \
+                     `prefix`, `section`, `part`, `sep`, `words`, `lines`, `grid`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    Ok((source, conversion))
+}
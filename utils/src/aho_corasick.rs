@@ -0,0 +1,194 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Range;
+
+/// Aho–Corasick automaton to find all occurrences of many patterns in one
+/// pass over a text.
+///
+/// Patterns and texts are sequences of symbols, e.g. the `char`s of a
+/// string. Positions in matches are symbol indices, not byte offsets.
+pub struct AhoCorasick<T> {
+    nodes: Vec<Node<T>>,
+    lens: Vec<usize>,
+}
+
+struct Node<T> {
+    next: HashMap<T, usize>,
+    fail: usize,
+    /// Patterns ending at this node, including those ending at a suffix.
+    out: Vec<usize>,
+}
+
+/// Occurrence of the pattern with index `pattern` at `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Match {
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl<T: Eq + Hash + Clone> AhoCorasick<T> {
+    /// Builds the automaton. Patterns are identified by their index, empty
+    /// patterns never match.
+    pub fn new<P, I>(patterns: P) -> Self
+    where
+        P: IntoIterator<Item = I>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut nodes = vec![Node::new()];
+        let mut lens = Vec::new();
+
+        for (id, pattern) in patterns.into_iter().enumerate() {
+            let mut cur = 0;
+            let mut len = 0;
+            for symbol in pattern {
+                cur = match nodes[cur].next.get(&symbol) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::new());
+                        let next = nodes.len() - 1;
+                        nodes[cur].next.insert(symbol, next);
+                        next
+                    }
+                };
+                len += 1;
+            }
+            if len > 0 {
+                nodes[cur].out.push(id);
+            }
+            lens.push(len);
+        }
+
+        // Breadth-first, so the failure link of a node's parent and the
+        // outputs of its own failure link are complete before the node.
+        let mut queue: VecDeque<usize> = nodes[0].next.values().copied().collect();
+        while let Some(cur) = queue.pop_front() {
+            let edges: Vec<(T, usize)> = nodes[cur]
+                .next
+                .iter()
+                .map(|(symbol, &next)| (symbol.clone(), next))
+                .collect();
+            for (symbol, next) in edges {
+                let mut fail = nodes[cur].fail;
+                let fail = loop {
+                    if let Some(&target) = nodes[fail].next.get(&symbol) {
+                        break target;
+                    }
+                    if fail == 0 {
+                        break 0;
+                    }
+                    fail = nodes[fail].fail;
+                };
+                nodes[next].fail = fail;
+                let inherited = nodes[fail].out.clone();
+                nodes[next].out.extend(inherited);
+                queue.push_back(next);
+            }
+        }
+        Self { nodes, lens }
+    }
+
+    /// Returns the number of patterns.
+    pub fn len(&self) -> usize {
+        self.lens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lens.is_empty()
+    }
+
+    /// Returns all occurrences of all patterns in `text`, including
+    /// overlapping ones, ordered by their end.
+    pub fn find_overlapping<I>(&self, text: I) -> Vec<Match>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut matches = Vec::new();
+        let mut cur = 0;
+
+        for (i, symbol) in text.into_iter().enumerate() {
+            cur = loop {
+                if let Some(&next) = self.nodes[cur].next.get(&symbol) {
+                    break next;
+                }
+                if cur == 0 {
+                    break 0;
+                }
+                cur = self.nodes[cur].fail;
+            };
+            for &pattern in &self.nodes[cur].out {
+                matches.push(Match {
+                    pattern,
+                    start: i + 1 - self.lens[pattern],
+                    end: i + 1,
+                });
+            }
+        }
+        matches
+    }
+}
+
+impl<T> Node<T> {
+    fn new() -> Self {
+        Self {
+            next: HashMap::new(),
+            fail: 0,
+            out: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(patterns: &[&str], text: &str) -> Vec<(usize, Range<usize>)> {
+        let ac = AhoCorasick::new(patterns.iter().map(|p| p.chars()));
+        let mut res: Vec<_> = ac
+            .find_overlapping(text.chars())
+            .iter()
+            .map(|m| (m.pattern, m.span()))
+            .collect();
+        res.sort_by_key(|(pattern, span)| (span.start, *pattern));
+        res
+    }
+
+    #[test]
+    fn classic() {
+        let res = spans(&["he", "she", "his", "hers"], "ushers");
+        assert_eq!(res, vec![(1, 1..4), (0, 2..4), (3, 2..6)]);
+    }
+
+    #[test]
+    fn overlapping() {
+        assert_eq!(
+            spans(&["AA"], "AAAA"),
+            vec![(0, 0..2), (0, 1..3), (0, 2..4)]
+        );
+        assert_eq!(spans(&["ABA"], "ABABA"), vec![(0, 0..3), (0, 2..5)]);
+    }
+
+    #[test]
+    fn nested_and_duplicate() {
+        let res = spans(&["ABC", "B", "B"], "ABC");
+        assert_eq!(res, vec![(0, 0..3), (1, 1..2), (2, 1..2)]);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(spans(&["", "X"], "XY"), vec![(1, 0..1)]);
+        assert_eq!(spans(&[], "XY"), vec![]);
+        assert_eq!(spans(&["X"], ""), vec![]);
+    }
+
+    #[test]
+    fn non_ascii() {
+        assert_eq!(spans(&["äö"], "aäöü"), vec![(0, 1..3)]);
+    }
+}
@@ -0,0 +1,125 @@
+use std::fmt;
+
+/// Answer of a quest part.
+///
+/// Answers compare equal across representations if they denote the same
+/// value, so `Answer::from(2323)`, `Answer::from(2323u128)` and
+/// `Answer::from("2323")` are all equal. Only strings in canonical form,
+/// without sign or leading zeros, are treated as numbers.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Str(String),
+}
+
+enum Key<'a> {
+    Num(i128),
+    Text(&'a str),
+}
+
+impl Answer {
+    fn key(&self) -> Key<'_> {
+        match self {
+            Answer::Int(n) => Key::Num(*n as i128),
+            Answer::BigInt(n) => Key::Num(*n),
+            Answer::Str(s) => match s.parse::<i128>() {
+                Ok(n) if n.to_string() == *s => Key::Num(n),
+                _ => Key::Text(s),
+            },
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.key(), other.key()) {
+            (Key::Num(a), Key::Num(b)) => a == b,
+            (Key::Text(a), Key::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Str(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Int(n),
+                        Err(_) => match i128::try_from(n) {
+                            Ok(n) => Answer::BigInt(n),
+                            Err(_) => Answer::Str(n.to_string()),
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Str(c.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn representations() {
+        assert!(matches!(Answer::from(5usize), Answer::Int(5)));
+        assert!(matches!(Answer::from(u64::MAX), Answer::BigInt(_)));
+        assert!(matches!(Answer::from(u128::MAX), Answer::Str(_)));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn equal_across_representations() {
+        assert_eq!(Answer::from(2323), Answer::from("2323"));
+        assert_eq!(Answer::from(2323), Answer::BigInt(2323));
+        assert_eq!(
+            Answer::from(4747374010031000u64),
+            Answer::from("4747374010031000")
+        );
+        assert_eq!(Answer::from(u128::MAX), Answer::from(u128::MAX.to_string()));
+        assert_eq!(Answer::from(-3), Answer::from("-3"));
+    }
+
+    #[test]
+    fn not_equal() {
+        assert_ne!(Answer::from(7), Answer::from("007"));
+        assert_ne!(Answer::from(7), Answer::from("+7"));
+        assert_ne!(Answer::from("RRB@"), Answer::from("RRB"));
+        assert_ne!(Answer::from(1), Answer::from(2u128));
+    }
+}
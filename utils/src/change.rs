@@ -0,0 +1,212 @@
+/// Coin change problems (unbounded knapsack) over a fixed set of coins.
+///
+/// The bottom-up tables are kept between calls and only extended when a
+/// larger target is asked for, so solving many targets costs no more than
+/// solving the largest one.
+pub struct Change {
+    /// Distinct coin values in ascending order.
+    coins: Vec<usize>,
+    /// For each target: minimum coin count and the last coin of one optimal
+    /// solution, or `None` if the target cannot be reached.
+    min: Vec<Option<(usize, usize)>>,
+    /// For each coin i: number of ways to reach each target using only the
+    /// coins up to and including i.
+    ways: Vec<Vec<u128>>,
+}
+
+impl Change {
+    /// Creates a solver for the given coins. Duplicates and zeros are ignored.
+    pub fn new(coins: &[usize]) -> Self {
+        let mut coins: Vec<usize> = coins.iter().copied().filter(|&c| c > 0).collect();
+        coins.sort_unstable();
+        coins.dedup();
+        Self {
+            ways: vec![Vec::new(); coins.len()],
+            coins,
+            min: Vec::new(),
+        }
+    }
+
+    pub fn coins(&self) -> &[usize] {
+        &self.coins
+    }
+
+    /// Returns the number of coins used by always taking the largest coin
+    /// that still fits, or `None` if that leaves a remainder.
+    ///
+    /// Only optimal for canonical coin systems, but needs no table.
+    pub fn greedy(&self, target: usize) -> Option<usize> {
+        let mut remaining = target;
+        let mut count = 0;
+
+        for coin in self.coins.iter().rev() {
+            count += remaining / coin;
+            remaining %= coin;
+        }
+        (remaining == 0).then_some(count)
+    }
+
+    /**
+    Returns the minimum number of coins summing to `target`, or `None` if
+    there is no solution.
+
+    - Subproblems: DP(t) = min amount of coins to sum to target t for t = 0,1,...,T
+    - Relation: DP(t) = min{1 + DP(t - c) | c <= t}
+    - Topological Order: increasing t
+    - Base Case: DP(0) = 0
+    - Original Problem: DP(T)
+    - Time: O(T) subproblems * O(C) times = O(T * C)
+    */
+    pub fn min_count(&mut self, target: usize) -> Option<usize> {
+        self.extend_min(target);
+        self.min[target].map(|(count, _)| count)
+    }
+
+    /// Returns one multiset of the fewest coins summing to `target`, in
+    /// descending order, or `None` if there is no solution.
+    pub fn min_coins(&mut self, target: usize) -> Option<Vec<usize>> {
+        self.extend_min(target);
+        let mut coins = Vec::new();
+        let mut remaining = target;
+
+        while remaining > 0 {
+            let (_, coin) = self.min[remaining]?;
+            coins.push(coin);
+            remaining -= coin;
+        }
+        coins.sort_unstable_by(|a, b| b.cmp(a));
+        Some(coins)
+    }
+
+    /// Returns the minimum total number of coins for two targets that sum to
+    /// `target` and differ by at most `max_diff`, or `None` if there is no
+    /// solution.
+    pub fn min_count_pair(&mut self, target: usize, max_diff: usize) -> Option<usize> {
+        let mut t1 = target / 2;
+        let mut t2 = t1 + target % 2;
+        let mut min: Option<usize> = None;
+
+        self.extend_min(target);
+        while t2 - t1 <= max_diff {
+            if let (Some(sol1), Some(sol2)) = (self.min_count(t1), self.min_count(t2)) {
+                min = Some(min.map_or(sol1 + sol2, |min| min.min(sol1 + sol2)));
+            }
+            if t1 == 0 {
+                break;
+            }
+            t1 -= 1;
+            t2 += 1;
+        }
+        min
+    }
+
+    /// Returns the number of distinct multisets of coins summing to `target`.
+    ///
+    /// Saturates at `u128::MAX`.
+    pub fn ways(&mut self, target: usize) -> u128 {
+        for i in 0..self.coins.len() {
+            let coin = self.coins[i];
+            for t in self.ways[i].len()..=target {
+                let without = match i {
+                    0 => (t == 0) as u128,
+                    _ => self.ways[i - 1][t],
+                };
+                let with = match t.checked_sub(coin) {
+                    Some(rest) => self.ways[i][rest],
+                    None => 0,
+                };
+                self.ways[i].push(without.saturating_add(with));
+            }
+        }
+        match self.ways.last() {
+            Some(ways) => ways[target],
+            None => (target == 0) as u128,
+        }
+    }
+
+    fn extend_min(&mut self, target: usize) {
+        for t in self.min.len()..=target {
+            let best = if t == 0 {
+                Some((0, 0))
+            } else {
+                self.coins
+                    .iter()
+                    .take_while(|&&coin| coin <= t)
+                    .filter_map(|&coin| self.min[t - coin].map(|(count, _)| (count + 1, coin)))
+                    .min_by_key(|&(count, _)| count)
+            };
+            self.min.push(best);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greedy() {
+        let change = Change::new(&[10, 5, 3, 1]);
+        assert_eq!(change.greedy(0), Some(0));
+        assert_eq!(change.greedy(16), Some(3));
+        assert_eq!(Change::new(&[4, 3]).greedy(6), None);
+    }
+
+    #[test]
+    fn min_count() {
+        let mut change = Change::new(&[1, 3, 5, 10, 15, 16, 20, 24, 25, 30]);
+        let res: Vec<_> = [2, 4, 7, 16].iter().map(|&t| change.min_count(t)).collect();
+        assert_eq!(res, vec![Some(2), Some(2), Some(3), Some(1)]);
+    }
+
+    #[test]
+    fn min_count_beats_greedy() {
+        let mut change = Change::new(&[1, 3, 4]);
+        assert_eq!(change.greedy(6), Some(3));
+        assert_eq!(change.min_count(6), Some(2));
+    }
+
+    #[test]
+    fn min_count_unreachable() {
+        let mut change = Change::new(&[4, 6]);
+        assert_eq!(change.min_count(0), Some(0));
+        assert_eq!(change.min_count(7), None);
+        assert_eq!(change.min_count(10), Some(2));
+    }
+
+    #[test]
+    fn min_coins() {
+        let mut change = Change::new(&[1, 3, 4]);
+        assert_eq!(change.min_coins(6), Some(vec![3, 3]));
+        assert_eq!(change.min_coins(0), Some(vec![]));
+        assert_eq!(Change::new(&[2]).min_coins(3), None);
+    }
+
+    #[test]
+    fn min_count_pair() {
+        let mut change = Change::new(&[1, 3, 5, 10]);
+        // 13 + 12 = (10 + 3) + (10 + 1 + 1)
+        assert_eq!(change.min_count_pair(25, 0), None);
+        assert_eq!(change.min_count_pair(25, 1), Some(5));
+        // 15 + 10 = (10 + 5) + (10)
+        assert_eq!(change.min_count_pair(25, 5), Some(3));
+        assert_eq!(change.min_count_pair(1, 10), Some(1));
+    }
+
+    #[test]
+    fn ways() {
+        let mut change = Change::new(&[1, 2, 5]);
+        assert_eq!(change.ways(0), 1);
+        assert_eq!(change.ways(5), 4);
+        assert_eq!(change.ways(3), 2);
+        assert_eq!(change.ways(100), 541);
+        assert_eq!(Change::new(&[]).ways(0), 1);
+        assert_eq!(Change::new(&[]).ways(1), 0);
+    }
+
+    #[test]
+    fn ignores_duplicates_and_zero() {
+        let change = Change::new(&[5, 0, 1, 5]);
+        assert_eq!(change.coins(), &[1, 5]);
+    }
+}
@@ -0,0 +1,348 @@
+use num_traits::{FromPrimitive, Num};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of the sequence `start, step(start), step(step(start)), ...` of an
+/// iterated state machine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of states before the first state that repeats.
+    pub prefix: usize,
+    /// Number of states in the repeating part.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Maps index `n` of the sequence to the index of the equal state within
+    /// the first `prefix + period` states.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. Needs constant memory, but calls `step` about
+/// three times as often as there are states before the cycle closes.
+pub fn floyd<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// Brent's algorithm. Needs constant memory and fewer calls to `step` than
+/// [`floyd`].
+pub fn brent<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// Incremental form of [`brent`] for sequences that are stepped elsewhere,
+/// e.g. by a loop that also looks for something else and may stop early.
+/// Feed it every state after the start, in order.
+#[derive(Clone, Debug)]
+pub struct Watch<S> {
+    tortoise: S,
+    power: usize,
+    period: usize,
+}
+
+impl<S: Clone + Eq> Watch<S> {
+    pub fn new(start: S) -> Self {
+        Self {
+            tortoise: start,
+            power: 1,
+            period: 1,
+        }
+    }
+
+    /// Takes the next state. Once it equals the state `period` states before
+    /// it, returns `period`, so the sequence repeats from `period` states
+    /// before `state` on.
+    pub fn push(&mut self, state: &S) -> Option<usize> {
+        if self.tortoise == *state {
+            return Some(self.period);
+        }
+        if self.power == self.period {
+            self.tortoise = state.clone();
+            self.power *= 2;
+            self.period = 0;
+        }
+        self.period += 1;
+        None
+    }
+}
+
+/// Returns the state at index `n` of the sequence without stepping through
+/// all of it.
+pub fn nth<S, F>(start: S, n: usize, mut step: F) -> S
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let cycle = brent(start.clone(), &mut step);
+    (0..cycle.index(n)).fold(start, |state, _| step(&state))
+}
+
+/// Records every state in a hash map until one repeats. Calls `step` once per
+/// distinct state, at the cost of keeping all of them.
+pub fn detect<S, F>(start: S, mut step: F) -> History<S>
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    loop {
+        if let Some(&prefix) = seen.get(&state) {
+            let period = states.len() - prefix;
+            return History {
+                states,
+                cycle: Cycle { prefix, period },
+            };
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// All distinct states of a sequence together with its cycle.
+#[derive(Clone, Debug)]
+pub struct History<S> {
+    states: Vec<S>,
+    cycle: Cycle,
+}
+
+impl<S> History<S> {
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// Returns the first `prefix + period` states, in order.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// Returns the state at index `n` of the sequence.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.index(n)]
+    }
+
+    /// Returns the sum of `f` over the first `n` states of the sequence.
+    pub fn sum<A, F>(&self, n: usize, f: F) -> A
+    where
+        A: Num + FromPrimitive + Copy,
+        F: Fn(&S) -> A,
+    {
+        let sum = |states: &[S]| states.iter().fold(A::zero(), |acc, s| acc + f(s));
+        let Cycle { prefix, period } = self.cycle;
+
+        if n <= prefix {
+            return sum(&self.states[..n]);
+        }
+        let repeats = (n - prefix) / period;
+        let rest = (n - prefix) % period;
+        let repeats = A::from_usize(repeats).expect("Repeat count does not fit into sum type");
+        sum(&self.states[..prefix])
+            + sum(&self.states[prefix..]) * repeats
+            + sum(&self.states[prefix..prefix + rest])
+    }
+
+    /// Returns the index of the `n`-th state (counting from 1) of the sequence
+    /// for which `pred` returns true, or `None` if there are fewer than `n`.
+    pub fn nth_match<P>(&self, n: usize, pred: P) -> Option<usize>
+    where
+        P: Fn(&S) -> bool,
+    {
+        let matches: Vec<usize> = (0..self.states.len())
+            .filter(|&i| pred(&self.states[i]))
+            .collect();
+        let (in_prefix, in_cycle) =
+            matches.split_at(matches.partition_point(|&i| i < self.cycle.prefix));
+
+        if n == 0 {
+            return None;
+        }
+        if n <= in_prefix.len() {
+            return Some(in_prefix[n - 1]);
+        }
+        if in_cycle.is_empty() {
+            return None;
+        }
+        let k = n - in_prefix.len() - 1;
+        Some(in_cycle[k % in_cycle.len()] + (k / in_cycle.len()) * self.cycle.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+    fn step(x: &usize) -> usize {
+        [1, 2, 3, 4, 2][*x]
+    }
+
+    const CYCLE: Cycle = Cycle {
+        prefix: 2,
+        period: 3,
+    };
+
+    #[test]
+    fn floyd_finds_cycle() {
+        assert_eq!(floyd(0, step), CYCLE);
+    }
+
+    #[test]
+    fn brent_finds_cycle() {
+        assert_eq!(brent(0, step), CYCLE);
+    }
+
+    #[test]
+    fn detect_finds_cycle() {
+        let history = detect(0, step);
+        assert_eq!(history.cycle(), CYCLE);
+        assert_eq!(history.states(), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn no_prefix() {
+        let step = |x: &u32| (x + 1) % 7;
+        let cycle = Cycle {
+            prefix: 0,
+            period: 7,
+        };
+        assert_eq!(floyd(0, step), cycle);
+        assert_eq!(brent(0, step), cycle);
+        assert_eq!(detect(0, step).cycle(), cycle);
+    }
+
+    #[test]
+    fn fixed_point() {
+        let cycle = Cycle {
+            prefix: 0,
+            period: 1,
+        };
+        assert_eq!(floyd(5, |&x| x), cycle);
+        assert_eq!(brent(5, |&x| x), cycle);
+        assert_eq!(detect(5, |&x| x).cycle(), cycle);
+    }
+
+    #[test]
+    fn all_detectors_agree() {
+        let step = |x: &u64| (x * x + 1) % 1_000_003;
+        let history = detect(3, step);
+        assert_eq!(floyd(3, step), history.cycle());
+        assert_eq!(brent(3, step), history.cycle());
+    }
+
+    #[test]
+    fn watch_finds_period() {
+        let mut watch = Watch::new(0);
+        let mut state = 0;
+        let period = loop {
+            state = step(&state);
+            if let Some(period) = watch.push(&state) {
+                break period;
+            }
+        };
+        assert_eq!(period, CYCLE.period);
+        assert_eq!(Watch::new(5).push(&5), Some(1));
+    }
+
+    #[test]
+    fn index() {
+        let indices: Vec<usize> = (0..9).map(|n| CYCLE.index(n)).collect();
+        assert_eq!(indices, vec![0, 1, 2, 3, 4, 2, 3, 4, 2]);
+    }
+
+    #[test]
+    fn nth_state() {
+        assert_eq!(nth(0, 1, step), 1);
+        assert_eq!(nth(0, 1_000_000_000_000, step), 4);
+        assert_eq!(*detect(0, step).nth(1_000_000_000_000), 4);
+    }
+
+    #[test]
+    fn sum() {
+        let history = detect(0, step);
+        let brute_force = |n: usize| (0..n).map(|i| *history.nth(i) as u64).sum::<u64>();
+        for n in 0..20 {
+            assert_eq!(history.sum(n, |&x| x as u64), brute_force(n));
+        }
+        // 0 + 1 + (2 + 3 + 4) * 10^12
+        assert_eq!(
+            history.sum(3_000_000_000_002, |&x| x as u64),
+            9_000_000_000_001
+        );
+    }
+
+    #[test]
+    fn nth_match() {
+        let history = detect(0, step);
+        assert_eq!(history.nth_match(1, |&x| x == 1), Some(1));
+        assert_eq!(history.nth_match(2, |&x| x == 1), None);
+        assert_eq!(history.nth_match(1, |&x| x == 3), Some(3));
+        assert_eq!(history.nth_match(3, |&x| x == 3), Some(9));
+        assert_eq!(history.nth_match(4, |&x| x >= 3), Some(7));
+        assert_eq!(history.nth_match(0, |&x| x == 3), None);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::grid::TraceError;
+use crate::parse::note::ParseError;
+use crate::parse::NumberError;
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+
+/// Error returned by the parts of a quest instead of panicking, so a runner
+/// can report it and carry on with the other parts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestError {
+    /// An input file could not be read.
+    Input(String),
+    /// The input could not be parsed.
+    Parse(String),
+    /// The input was parsed, but breaks an assumption of the solution.
+    Invalid(String),
+    /// The solution found no answer for the input.
+    NoSolution(String),
+}
+
+pub type Result<T> = std::result::Result<T, QuestError>;
+
+impl QuestError {
+    pub fn input(message: impl fmt::Display) -> Self {
+        QuestError::Input(message.to_string())
+    }
+
+    pub fn parse(message: impl fmt::Display) -> Self {
+        QuestError::Parse(message.to_string())
+    }
+
+    pub fn invalid(message: impl fmt::Display) -> Self {
+        QuestError::Invalid(message.to_string())
+    }
+
+    pub fn no_solution(message: impl fmt::Display) -> Self {
+        QuestError::NoSolution(message.to_string())
+    }
+
+    /// Prefixes the message with the step that failed, e.g. `"nails: ..."`.
+    pub fn context(self, step: impl fmt::Display) -> Self {
+        match self {
+            QuestError::Input(msg) => QuestError::Input(format!("{step}: {msg}")),
+            QuestError::Parse(msg) => QuestError::Parse(format!("{step}: {msg}")),
+            QuestError::Invalid(msg) => QuestError::Invalid(format!("{step}: {msg}")),
+            QuestError::NoSolution(msg) => QuestError::NoSolution(format!("{step}: {msg}")),
+        }
+    }
+}
+
+impl fmt::Display for QuestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuestError::Input(msg) => write!(f, "input error: {msg}"),
+            QuestError::Parse(msg) => write!(f, "parse error: {msg}"),
+            QuestError::Invalid(msg) => write!(f, "invalid input: {msg}"),
+            QuestError::NoSolution(msg) => write!(f, "no solution: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for QuestError {}
+
+impl From<ParseError> for QuestError {
+    fn from(err: ParseError) -> Self {
+        QuestError::parse(err)
+    }
+}
+
+impl From<NumberError> for QuestError {
+    fn from(err: NumberError) -> Self {
+        QuestError::parse(err)
+    }
+}
+
+impl From<ParseIntError> for QuestError {
+    fn from(err: ParseIntError) -> Self {
+        QuestError::parse(err)
+    }
+}
+
+impl From<ParseFloatError> for QuestError {
+    fn from(err: ParseFloatError) -> Self {
+        QuestError::parse(err)
+    }
+}
+
+impl From<TraceError> for QuestError {
+    fn from(err: TraceError) -> Self {
+        QuestError::invalid(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let err = QuestError::parse("expected a number").context("nails");
+        assert_eq!(err.to_string(), "parse error: nails: expected a number");
+        let err = QuestError::no_solution("no unique path");
+        assert_eq!(err.to_string(), "no solution: no unique path");
+    }
+
+    #[test]
+    fn from_parse_int() {
+        let res: Result<u32> = "x".parse::<u32>().map_err(QuestError::from);
+        assert!(matches!(res, Err(QuestError::Parse(_))));
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// An Everybody Codes event: a yearly tournament or a story.
+///
+/// Each event has its own directory in the workspace root, named after
+/// [`Event`]'s `Display`, e.g. `2024` or `story1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Event {
+    Year(u16),
+    Story(u8),
+}
+
+/// A quest of an event, e.g. quest 1 of 2024.
+///
+/// Its crate lives in `<event>/questNN` and is named `<prefix>_questNN`,
+/// e.g. `2024/quest01` and `ec2024_quest01`, so quests of different events
+/// don't collide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuestId {
+    pub event: Event,
+    pub quest: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError(String);
+
+impl Event {
+    /// Returns the prefix of the package names of this event's quests.
+    pub fn prefix(&self) -> String {
+        match self {
+            Event::Year(year) => format!("ec{year}"),
+            Event::Story(story) => format!("story{story}"),
+        }
+    }
+
+    fn from_prefix(s: &str) -> Option<Self> {
+        if let Some(year) = s.strip_prefix("ec") {
+            year.parse().ok().map(Event::Year)
+        } else {
+            s.parse().ok()
+        }
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Year(year) => write!(f, "{year}"),
+            Event::Story(story) => write!(f, "story{story}"),
+        }
+    }
+}
+
+impl FromStr for Event {
+    type Err = ParseIdError;
+
+    /// Parses `2024` or `story1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseIdError(format!("Invalid event: {s}"));
+        match s.strip_prefix("story") {
+            Some(story) => match story.parse() {
+                Ok(story) if story > 0 => Ok(Event::Story(story)),
+                _ => Err(err()),
+            },
+            None => match s.parse() {
+                Ok(year) if year >= 1000 => Ok(Event::Year(year)),
+                _ => Err(err()),
+            },
+        }
+    }
+}
+
+impl QuestId {
+    pub fn new(event: Event, quest: u8) -> Self {
+        Self { event, quest }
+    }
+
+    /// Returns the crate directory relative to the workspace root.
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(self.event.to_string()).join(format!("quest{:02}", self.quest))
+    }
+
+    /// Returns the package name, which is also the name of the binary.
+    pub fn package(&self) -> String {
+        format!("{}_quest{:02}", self.event.prefix(), self.quest)
+    }
+
+    /// Parses a package name like `ec2024_quest01`.
+    ///
+    /// A trailing `-<hash>`, as in the names of test binaries, is ignored.
+    pub fn from_package(name: &str) -> Option<Self> {
+        let name = name.split('-').next()?;
+        let (prefix, quest) = name.split_once("_quest")?;
+        let event = Event::from_prefix(prefix)?;
+        match quest.parse() {
+            Ok(quest) if quest > 0 => Some(Self::new(event, quest)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for QuestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} quest {:02}", self.event, self.quest)
+    }
+}
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ParseIdError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_event() {
+        assert_eq!("2024".parse(), Ok(Event::Year(2024)));
+        assert_eq!("story1".parse(), Ok(Event::Story(1)));
+        assert!("story0".parse::<Event>().is_err());
+        assert!("24".parse::<Event>().is_err());
+        assert!("quest01".parse::<Event>().is_err());
+    }
+
+    #[test]
+    fn layout() {
+        let id = QuestId::new(Event::Year(2024), 1);
+        assert_eq!(id.dir(), PathBuf::from("2024/quest01"));
+        assert_eq!(id.package(), "ec2024_quest01");
+        assert_eq!(id.to_string(), "2024 quest 01");
+
+        let id = QuestId::new(Event::Story(1), 3);
+        assert_eq!(id.dir(), PathBuf::from("story1/quest03"));
+        assert_eq!(id.package(), "story1_quest03");
+    }
+
+    #[test]
+    fn from_package() {
+        let id = QuestId::new(Event::Year(2025), 12);
+        assert_eq!(QuestId::from_package("ec2025_quest12"), Some(id));
+        assert_eq!(QuestId::from_package("ec2025_quest12-0f3c9a1b2d"), Some(id));
+        assert_eq!(
+            QuestId::from_package("story2_quest03"),
+            Some(QuestId::new(Event::Story(2), 3))
+        );
+        assert_eq!(QuestId::from_package("quest01"), None);
+        assert_eq!(QuestId::from_package("utils-0f3c9a1b2d"), None);
+    }
+}
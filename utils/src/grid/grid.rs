@@ -1,4 +1,6 @@
+use crate::grid::metric::Metric;
 use crate::grid::position::Position;
+use std::collections::VecDeque;
 
 pub struct Grid<T> {
     pub width: usize,
@@ -16,11 +18,11 @@ impl<T> Grid<T> {
     }
 
     pub fn get(&self, pos: &Position) -> Option<&T> {
-        Some(self.data.get(pos.row)?.get(pos.col)?)
+        self.data.get(pos.row)?.get(pos.col)
     }
 
     pub fn get_mut(&mut self, pos: &Position) -> Option<&mut T> {
-        Some(self.data.get_mut(pos.row)?.get_mut(pos.col)?)
+        self.data.get_mut(pos.row)?.get_mut(pos.col)
     }
 
     pub fn put(&mut self, pos: &Position, to_put: T) -> bool {
@@ -57,6 +59,87 @@ impl<T> Grid<T> {
                 .map(move |(col, value)| (Position::new(row, col), value))
         })
     }
+
+    /// Returns a grid of the same shape with `f` applied to each element.
+    pub fn map<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(row, row_vec)| {
+                    row_vec
+                        .iter()
+                        .enumerate()
+                        .map(|(col, value)| f(Position::new(row, col), value))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns an iterator over the neighbors of `pos` under the given metric,
+    /// skipping positions outside the grid.
+    pub fn neighbors(&self, pos: &Position, metric: Metric) -> impl Iterator<Item = Position> + '_ {
+        let pos = *pos;
+        metric
+            .offsets()
+            .iter()
+            .filter_map(move |&(row, col)| pos.offset(row, col))
+            .filter(|neighbor| self.get(neighbor).is_some())
+    }
+
+    /// Computes the distance from the nearest source to every cell with a
+    /// single multi-source BFS.
+    ///
+    /// Sources have distance 0. From there, only cells for which `passable`
+    /// returns true are entered. Cells that cannot be reached are `None`.
+    pub fn distance_field<P>(
+        &self,
+        sources: impl IntoIterator<Item = Position>,
+        passable: P,
+        metric: Metric,
+    ) -> Grid<Option<usize>>
+    where
+        P: Fn(&Position, &T) -> bool,
+    {
+        self.distance_field_by(sources, passable, metric.offsets())
+    }
+
+    /// Like [`Grid::distance_field`], but steps by the given (row, col)
+    /// offsets, e.g. those of a [`Neighborhood`](super::Neighborhood).
+    pub fn distance_field_by<P>(
+        &self,
+        sources: impl IntoIterator<Item = Position>,
+        passable: P,
+        steps: &[(isize, isize)],
+    ) -> Grid<Option<usize>>
+    where
+        P: Fn(&Position, &T) -> bool,
+    {
+        let mut distances = self.map(|_, _| None);
+        let mut queue = VecDeque::new();
+
+        for source in sources {
+            if distances.put(&source, Some(0)) {
+                queue.push_back((source, 0));
+            }
+        }
+        while let Some((pos, dist)) = queue.pop_front() {
+            for neighbor in steps.iter().filter_map(|&(row, col)| pos.offset(row, col)) {
+                let Some(entry) = distances.get_mut(&neighbor) else {
+                    continue;
+                };
+                if entry.is_none() && passable(&neighbor, self.get(&neighbor).unwrap()) {
+                    *entry = Some(dist + 1);
+                    queue.push_back((neighbor, dist + 1));
+                }
+            }
+        }
+        distances
+    }
 }
 
 impl From<&str> for Grid<char> {
@@ -64,3 +147,85 @@ impl From<&str> for Grid<char> {
         Self::new(value.lines().map(|line| line.chars().collect()).collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(input: &str, metric: Metric) -> Vec<Vec<Option<usize>>> {
+        let grid = Grid::from(input);
+        let sources = grid
+            .iter()
+            .filter(|(_, &c)| c == 'S')
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        grid.distance_field(sources, |_, &c| c == '.', metric).data
+    }
+
+    #[test]
+    fn distance_field_manhattan() {
+        let res = field("S..\n.#.\n...", Metric::Manhattan);
+        assert_eq!(
+            res,
+            vec![
+                vec![Some(0), Some(1), Some(2)],
+                vec![Some(1), None, Some(3)],
+                vec![Some(2), Some(3), Some(4)],
+            ]
+        );
+    }
+
+    #[test]
+    fn distance_field_chebyshev() {
+        let res = field("S..\n.#.\n...", Metric::Chebyshev);
+        assert_eq!(
+            res,
+            vec![
+                vec![Some(0), Some(1), Some(2)],
+                vec![Some(1), None, Some(2)],
+                vec![Some(2), Some(2), Some(3)],
+            ]
+        );
+    }
+
+    #[test]
+    fn distance_field_multiple_sources() {
+        let res = field("S...S", Metric::Manhattan);
+        assert_eq!(res, vec![vec![Some(0), Some(1), Some(2), Some(1), Some(0)]]);
+    }
+
+    #[test]
+    fn distance_field_unreachable() {
+        let res = field("S#.", Metric::Chebyshev);
+        assert_eq!(res, vec![vec![Some(0), None, None]]);
+    }
+
+    #[test]
+    fn distance_field_hex() {
+        let res = field("S..\n...", Metric::Hex);
+        assert_eq!(
+            res,
+            vec![
+                vec![Some(0), Some(1), Some(2)],
+                vec![Some(1), Some(2), Some(3)]
+            ]
+        );
+    }
+
+    #[test]
+    fn distance_field_custom() {
+        let grid = Grid::from("..S..");
+        let start = [Position::new(0, 2)];
+        let res = grid.distance_field_by(start, |_, _| true, &[(0, 2)]).data;
+        assert_eq!(res, vec![vec![None, None, Some(0), None, Some(1)]]);
+
+        let res = grid.distance_field_by(start, |_, _| true, &[(0, -1)]).data;
+        assert_eq!(res, vec![vec![Some(2), Some(1), Some(0), None, None]]);
+    }
+
+    #[test]
+    fn distance_field_no_sources() {
+        let res = field("...", Metric::Manhattan);
+        assert_eq!(res, vec![vec![None, None, None]]);
+    }
+}
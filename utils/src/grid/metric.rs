@@ -0,0 +1,33 @@
+/// Neighborhood used to step from one cell to the next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// 4-way steps (Up, Right, Down, Left), distances are Manhattan
+    /// distances.
+    Manhattan,
+    /// 8-way steps including diagonals, distances are Chebyshev distances.
+    Chebyshev,
+    /// 6-way steps on a hex grid in axial coordinates, where each row is
+    /// shifted half a cell to the right of the row above.
+    Hex,
+}
+
+impl Metric {
+    /// Returns the (row, col) offsets of all neighbors in clockwise order,
+    /// starting with Up, or Up-Right for [`Metric::Hex`].
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Metric::Manhattan => &[(-1, 0), (0, 1), (1, 0), (0, -1)],
+            Metric::Chebyshev => &[
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+            ],
+            Metric::Hex => &[(-1, 1), (0, 1), (1, 0), (1, -1), (0, -1), (-1, 0)],
+        }
+    }
+}
@@ -1,5 +1,14 @@
+#[allow(clippy::module_inception)]
 mod grid;
+mod metric;
+mod neighborhood;
 mod position;
+mod search;
+mod trace;
 
 pub use grid::Grid;
+pub use metric::Metric;
+pub use neighborhood::{Neighborhood, ParseNeighborhoodError};
 pub use position::Position;
+pub use search::{Heading, WordMatch, WordSearch};
+pub use trace::{trace_loop, TraceError};
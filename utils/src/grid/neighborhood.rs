@@ -0,0 +1,117 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::Metric;
+
+/// Cells that count as neighbors of a cell, given as (row, col) offsets.
+///
+/// Unlike a [`Metric`], a neighborhood does not have to be symmetric, so it
+/// can describe any stencil, e.g. one parsed from the command line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Neighborhood {
+    offsets: Vec<(isize, isize)>,
+}
+
+impl Neighborhood {
+    pub fn new(offsets: Vec<(isize, isize)>) -> Self {
+        Self { offsets }
+    }
+
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        &self.offsets
+    }
+
+    /// Returns the offsets that lead back to a cell from its neighbors.
+    pub fn reversed(&self) -> Self {
+        Self::new(self.offsets.iter().map(|&(r, c)| (-r, -c)).collect())
+    }
+
+    /// Returns the largest distance along a row or column that a single
+    /// step can cover.
+    pub fn reach(&self) -> usize {
+        self.offsets
+            .iter()
+            .map(|&(r, c)| r.unsigned_abs().max(c.unsigned_abs()))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl From<Metric> for Neighborhood {
+    fn from(metric: Metric) -> Self {
+        Self::new(metric.offsets().to_vec())
+    }
+}
+
+impl FromStr for Neighborhood {
+    type Err = ParseNeighborhoodError;
+
+    /// Parses `von-neumann`, `moore`, `hex` or a list of (row, col) offsets
+    /// like `0,1;1,0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseNeighborhoodError(format!("Invalid neighborhood: {s}"));
+        match s {
+            "von-neumann" => Ok(Metric::Manhattan.into()),
+            "moore" => Ok(Metric::Chebyshev.into()),
+            "hex" => Ok(Metric::Hex.into()),
+            _ => s
+                .split(';')
+                .map(|offset| {
+                    let (row, col) = offset.split_once(',').ok_or_else(err)?;
+                    let row = row.trim().parse().map_err(|_| err())?;
+                    let col = col.trim().parse().map_err(|_| err())?;
+                    Ok((row, col))
+                })
+                .collect::<Result<_, _>>()
+                .map(Self::new),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNeighborhoodError(String);
+
+impl fmt::Display for ParseNeighborhoodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ParseNeighborhoodError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_named() {
+        let res = "von-neumann".parse::<Neighborhood>();
+        assert_eq!(res, Ok(Metric::Manhattan.into()));
+        let res = "moore".parse::<Neighborhood>();
+        assert_eq!(res, Ok(Metric::Chebyshev.into()));
+        let res = "hex".parse::<Neighborhood>();
+        assert_eq!(res, Ok(Metric::Hex.into()));
+    }
+
+    #[test]
+    fn parse_offsets() {
+        let res = "0,2; -1,0".parse::<Neighborhood>();
+        assert_eq!(res, Ok(Neighborhood::new(vec![(0, 2), (-1, 0)])));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("".parse::<Neighborhood>().is_err());
+        assert!("0,1;".parse::<Neighborhood>().is_err());
+        assert!("0;1".parse::<Neighborhood>().is_err());
+        assert!("knight".parse::<Neighborhood>().is_err());
+    }
+
+    #[test]
+    fn reversed_and_reach() {
+        let neighborhood = Neighborhood::new(vec![(0, 2), (-1, 0)]);
+        let reversed = Neighborhood::new(vec![(0, -2), (1, 0)]);
+        assert_eq!(neighborhood.reversed(), reversed);
+        assert_eq!(neighborhood.reach(), 2);
+    }
+}
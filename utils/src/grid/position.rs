@@ -44,6 +44,15 @@ impl Position {
         .filter(move |pos| max.contains(pos))
     }
 
+    /// Returns the position shifted by the given row and column offsets, or
+    /// `None` if the result would have a negative row or column value.
+    pub fn offset(&self, row: isize, col: isize) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add_signed(row)?,
+            self.col.checked_add_signed(col)?,
+        ))
+    }
+
     // fn to(&self, direction: Direction) -> Self {
     //     match direction {
     //         Direction::Up => Self::new(self.row - 1, self.col),
@@ -101,6 +110,14 @@ mod tests {
         assert!(!bounds.contains(&Position::new(6, 6)));
     }
 
+    #[test]
+    fn offset() {
+        let pos = Position::new(1, 1);
+        assert_eq!(pos.offset(-1, 2), Some(Position::new(0, 3)));
+        assert_eq!(pos.offset(-2, 0), None);
+        assert_eq!(pos.offset(0, -2), None);
+    }
+
     #[test]
     fn neighbors_middle() {
         let pos = Position::new(2, 2);
@@ -0,0 +1,234 @@
+use crate::grid::{Grid, Metric, Position};
+use std::collections::HashSet;
+
+/// One of the eight directions a word can be read in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Heading {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Heading {
+    /// All headings in clockwise order, starting with Up.
+    pub const ALL: [Heading; 8] = [
+        Heading::Up,
+        Heading::UpRight,
+        Heading::Right,
+        Heading::DownRight,
+        Heading::Down,
+        Heading::DownLeft,
+        Heading::Left,
+        Heading::UpLeft,
+    ];
+
+    /// Horizontal and vertical headings only.
+    pub const STRAIGHT: [Heading; 4] = [Heading::Up, Heading::Right, Heading::Down, Heading::Left];
+
+    /// Returns the (row, col) offset of one step.
+    pub fn step(&self) -> (isize, isize) {
+        // The variants and the Chebyshev offsets are both in clockwise order,
+        // starting with Up.
+        Metric::Chebyshev.offsets()[*self as usize]
+    }
+
+    pub fn reverse(&self) -> Self {
+        Heading::ALL[(*self as usize + 4) % 8]
+    }
+}
+
+/// Occurrence of the word with index `word`, read from `cells[0]` in the
+/// direction of `heading`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordMatch {
+    pub word: usize,
+    pub heading: Heading,
+    pub cells: Vec<Position>,
+}
+
+/// Word search over a [`Grid`], configured with the headings words may be
+/// read in and whether rows and columns wrap around.
+#[derive(Clone, Debug)]
+pub struct WordSearch {
+    headings: Vec<Heading>,
+    wrap_horizontal: bool,
+    wrap_vertical: bool,
+}
+
+impl WordSearch {
+    /// Searches in the given headings without wrapping.
+    pub fn new(headings: &[Heading]) -> Self {
+        Self {
+            headings: headings.to_vec(),
+            wrap_horizontal: false,
+            wrap_vertical: false,
+        }
+    }
+
+    /// Lets words continue on the other side of a row.
+    pub fn wrap_horizontal(mut self, wrap: bool) -> Self {
+        self.wrap_horizontal = wrap;
+        self
+    }
+
+    /// Lets words continue on the other side of a column.
+    pub fn wrap_vertical(mut self, wrap: bool) -> Self {
+        self.wrap_vertical = wrap;
+        self
+    }
+
+    /// Returns the positions of all cells that are part of an occurrence of
+    /// any word. Empty words are ignored.
+    pub fn find<T, W, I>(&self, grid: &Grid<T>, words: W) -> HashSet<Position>
+    where
+        T: PartialEq,
+        W: IntoIterator<Item = I>,
+        I: IntoIterator<Item = T>,
+    {
+        self.find_matches(grid, words)
+            .into_iter()
+            .flat_map(|m| m.cells)
+            .collect()
+    }
+
+    /// Returns every occurrence of every word, see [`WordSearch::find`].
+    pub fn find_matches<T, W, I>(&self, grid: &Grid<T>, words: W) -> Vec<WordMatch>
+    where
+        T: PartialEq,
+        W: IntoIterator<Item = I>,
+        I: IntoIterator<Item = T>,
+    {
+        let words: Vec<(usize, Vec<T>)> = words
+            .into_iter()
+            .map(|word| word.into_iter().collect::<Vec<T>>())
+            .enumerate()
+            .filter(|(_, word)| !word.is_empty())
+            .collect();
+        let mut matches = Vec::new();
+
+        for (start, first) in grid.iter() {
+            for &heading in &self.headings {
+                for (index, word) in words.iter().filter(|(_, word)| word[0] == *first) {
+                    let mut cells = Vec::with_capacity(word.len());
+                    let mut pos = Some(start);
+                    for symbol in word {
+                        match pos.filter(|pos| grid.get(pos) == Some(symbol)) {
+                            Some(cur) => {
+                                cells.push(cur);
+                                pos = self.step(grid, cur, heading);
+                            }
+                            None => break,
+                        }
+                    }
+                    if cells.len() == word.len() {
+                        matches.push(WordMatch {
+                            word: *index,
+                            heading,
+                            cells,
+                        });
+                    }
+                }
+            }
+        }
+        matches
+    }
+
+    fn step<T>(&self, grid: &Grid<T>, pos: Position, heading: Heading) -> Option<Position> {
+        let (row, col) = heading.step();
+        let row = wrap(pos.row, row, grid.height, self.wrap_vertical)?;
+        let col = wrap(pos.col, col, grid.width, self.wrap_horizontal)?;
+        Some(Position::new(row, col))
+    }
+}
+
+fn wrap(index: usize, step: isize, len: usize, wrap: bool) -> Option<usize> {
+    if wrap {
+        Some((index as isize + step).rem_euclid(len as isize) as usize)
+    } else {
+        index.checked_add_signed(step).filter(|&index| index < len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marked(search: &WordSearch, grid: &str, words: &[&str]) -> Vec<(usize, usize)> {
+        let grid = Grid::from(grid);
+        let mut res: Vec<_> = search
+            .find(&grid, words.iter().map(|word| word.chars()))
+            .iter()
+            .map(|pos| (pos.row, pos.col))
+            .collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn headings() {
+        let grid = "ABC\nDEF\nGHI";
+        let right = WordSearch::new(&[Heading::Right]);
+        assert_eq!(marked(&right, grid, &["EF", "FE"]), vec![(1, 1), (1, 2)]);
+
+        let diagonal = WordSearch::new(&[Heading::UpLeft]);
+        assert_eq!(
+            marked(&diagonal, grid, &["IEA"]),
+            vec![(0, 0), (1, 1), (2, 2)]
+        );
+
+        let all = WordSearch::new(&Heading::ALL);
+        assert_eq!(marked(&all, grid, &["CEG", "HEB"]).len(), 5);
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = "ABC\nDEF\nGHI";
+        let no_wrap = WordSearch::new(&Heading::STRAIGHT);
+        assert!(marked(&no_wrap, grid, &["CA", "GA"]).is_empty());
+
+        let horizontal = no_wrap.clone().wrap_horizontal(true);
+        assert_eq!(
+            marked(&horizontal, grid, &["CA", "GA"]),
+            vec![(0, 0), (0, 2)]
+        );
+
+        let vertical = no_wrap.wrap_vertical(true);
+        assert_eq!(marked(&vertical, grid, &["CA", "GA"]), vec![(0, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn matches() {
+        let grid = Grid::from("ABA\nxxB");
+        let search = WordSearch::new(&Heading::ALL);
+        let mut res = search.find_matches(&grid, ["", "AB"].iter().map(|word| word.chars()));
+        res.sort_by_key(|m| m.cells[0].col);
+
+        assert_eq!(res.len(), 3);
+        assert_eq!(res[0].word, 1);
+        assert_eq!(res[0].heading, Heading::Right);
+        assert_eq!(res[2].heading, Heading::Left);
+        assert_eq!(res[2].cells, vec![Position::new(0, 2), Position::new(0, 1)]);
+    }
+
+    #[test]
+    fn steps() {
+        assert_eq!(Heading::Up.step(), (-1, 0));
+        assert_eq!(Heading::DownLeft.step(), (1, -1));
+        let straight: Vec<_> = Heading::STRAIGHT.iter().map(Heading::step).collect();
+        assert_eq!(straight, Metric::Manhattan.offsets());
+    }
+
+    #[test]
+    fn reverse() {
+        for heading in Heading::ALL {
+            let (row, col) = heading.step();
+            assert_eq!(heading.reverse().step(), (-row, -col));
+            assert_eq!(heading.reverse().reverse(), heading);
+        }
+    }
+}
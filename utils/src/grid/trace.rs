@@ -0,0 +1,141 @@
+use crate::grid::{Grid, Metric, Position};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceError {
+    /// The start position is outside the grid or not part of the track.
+    NotOnTrack(Position),
+    /// The start has fewer than two track neighbors, so no loop can pass
+    /// through it.
+    OpenPath(Position),
+    /// The track ends at this position without returning to the start.
+    DeadEnd(Position),
+    /// The track splits into more than one direction at this position.
+    Fork(Position),
+}
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceError::NotOnTrack(pos) => {
+                write!(f, "start ({}, {}) is not on the track", pos.row, pos.col)
+            }
+            TraceError::OpenPath(pos) => {
+                write!(f, "track through ({}, {}) is not closed", pos.row, pos.col)
+            }
+            TraceError::DeadEnd(pos) => write!(f, "dead end at ({}, {})", pos.row, pos.col),
+            TraceError::Fork(pos) => write!(f, "fork at ({}, {})", pos.row, pos.col),
+        }
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+/// Follows the closed track through `start` and returns its positions in
+/// order, beginning with `start`.
+///
+/// Cells for which `is_track` returns true belong to the track and are
+/// connected 4-way. Every cell of the loop must have exactly two track
+/// neighbors. The direction of travel is towards the first neighbor of
+/// `start` in clockwise order (Up, Right, Down, Left).
+pub fn trace_loop<T>(
+    grid: &Grid<T>,
+    start: Position,
+    is_track: impl Fn(&T) -> bool,
+) -> Result<Vec<Position>, TraceError> {
+    let on_track = |pos: &Position| grid.get(pos).is_some_and(&is_track);
+    let track_neighbors = |pos: &Position| {
+        grid.neighbors(pos, Metric::Manhattan)
+            .filter(|p| on_track(p))
+            .collect::<Vec<_>>()
+    };
+
+    if !on_track(&start) {
+        return Err(TraceError::NotOnTrack(start));
+    }
+    let mut cur_pos = match track_neighbors(&start)[..] {
+        [first, _] => first,
+        [] | [_] => return Err(TraceError::OpenPath(start)),
+        _ => return Err(TraceError::Fork(start)),
+    };
+    let mut prev_pos = start;
+    let mut path = vec![start];
+
+    while cur_pos != start {
+        path.push(cur_pos);
+        let next = match track_neighbors(&cur_pos)
+            .into_iter()
+            .filter(|p| *p != prev_pos)
+            .collect::<Vec<_>>()[..]
+        {
+            [next] => next,
+            [] => return Err(TraceError::DeadEnd(cur_pos)),
+            _ => return Err(TraceError::Fork(cur_pos)),
+        };
+        (prev_pos, cur_pos) = (cur_pos, next);
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(input: &str) -> Result<Vec<Position>, TraceError> {
+        let grid = Grid::from(input);
+        let start = grid
+            .iter()
+            .find_map(|(p, &c)| (c == 'S').then_some(p))
+            .unwrap();
+        trace_loop(&grid, start, |c| !c.is_whitespace())
+    }
+
+    #[test]
+    fn closed_loop() {
+        let res = trace("S+=\n- +\n=-+");
+        assert_eq!(
+            res,
+            Ok(vec![
+                Position::new(0, 0),
+                Position::new(0, 1),
+                Position::new(0, 2),
+                Position::new(1, 2),
+                Position::new(2, 2),
+                Position::new(2, 1),
+                Position::new(2, 0),
+                Position::new(1, 0),
+            ])
+        );
+    }
+
+    #[test]
+    fn ragged_lines() {
+        let res = trace("+S\n++");
+        assert_eq!(res.map(|path| path.len()), Ok(4));
+    }
+
+    #[test]
+    fn not_on_track() {
+        let grid = Grid::from("S+\n  ");
+        let res = trace_loop(&grid, Position::new(1, 0), |c| !c.is_whitespace());
+        assert_eq!(res, Err(TraceError::NotOnTrack(Position::new(1, 0))));
+    }
+
+    #[test]
+    fn open_path() {
+        let res = trace("S++");
+        assert_eq!(res, Err(TraceError::OpenPath(Position::new(0, 0))));
+    }
+
+    #[test]
+    fn dead_end() {
+        let res = trace("S++\n- +\n=  ");
+        assert_eq!(res, Err(TraceError::DeadEnd(Position::new(1, 2))));
+    }
+
+    #[test]
+    fn fork() {
+        let res = trace("S+=+\n- ++\n=-+ ");
+        assert_eq!(res, Err(TraceError::Fork(Position::new(0, 2))));
+    }
+}
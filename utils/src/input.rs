@@ -1,46 +1,135 @@
+use crate::error::{QuestError, Result};
+use crate::event::QuestId;
 use std::env;
 use std::fs;
 use std::io;
+use std::iter;
+use std::ops::Index;
 use std::path;
 
 pub fn read_file(filename: &str) -> String {
-    let dir = current_mod_dir().expect("Failed to get directory of current module");
+    try_read_file(filename).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Like [`read_file`], but returns an error instead of panicking.
+pub fn try_read_file(filename: &str) -> Result<String> {
+    let dir = current_mod_dir().map_err(|err| {
+        QuestError::input(format!("Failed to get directory of current module: {err}"))
+    })?;
     let path = dir.join(filename);
-    fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read file {}", path.display()))
+    fs::read_to_string(&path)
+        .map_err(|err| QuestError::input(format!("Failed to read file {}: {err}", path.display())))
 }
 
+/// Returns the crate directory of the running binary.
+///
+/// The crate is derived from the binary name, see [`QuestId::from_package`],
+/// and looked up from the working directory and the binary upwards, so this
+/// works for `cargo run`, `cargo test` and binaries started directly.
 fn current_mod_dir() -> io::Result<path::PathBuf> {
     let cwd = env::current_dir()?;
     let exe = env::current_exe()?;
     let module = exe
-        .file_name()
+        .file_stem()
         .and_then(|f| f.to_str())
-        .map(|s| {
-            s.chars()
-                .take_while(|c| c.is_alphanumeric())
-                .collect::<String>()
-        })
+        .and_then(|s| s.split('-').next())
         .unwrap_or_default();
-    let mut res = cwd.clone();
-
-    loop {
-        if let Some(component) = res.file_name() {
-            if exe
-                .components()
-                .rev()
-                .skip(1)
-                .any(|d| d.as_os_str() == component)
-            {
-                break;
-            }
-        }
-        if !res.pop() {
-            res = cwd;
-            break;
+    let rel = match QuestId::from_package(module) {
+        Some(id) => id.dir(),
+        None => path::PathBuf::from(module),
+    };
+
+    let res = cwd
+        .ancestors()
+        .chain(exe.ancestors().skip(1))
+        .map(|dir| dir.join(&rel))
+        .find(|dir| dir.is_dir())
+        .unwrap_or_else(|| cwd.join(&rel));
+    Ok(res)
+}
+
+/// Converts CRLF and lone CR line endings to LF and removes trailing
+/// whitespace from every line.
+pub fn normalize(input: &str) -> String {
+    input
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Splits the input into blocks separated by one or more blank lines and
+/// normalizes each block.
+pub fn sections(input: &str) -> Sections {
+    let blocks = blocks(input).map(normalize).collect();
+    Sections { blocks }
+}
+
+/// Splits `text` into blocks separated by one or more blank lines, which are
+/// empty or only whitespace. Lines end with LF, CRLF or a lone CR.
+///
+/// Each block is the subslice of `text` from the start of its first line to
+/// the end of its last line, so parsers can still locate it in the note.
+pub(crate) fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    let mut lines = text
+        .split('\n')
+        .flat_map(|line| line.strip_suffix('\r').unwrap_or(line).split('\r'))
+        .peekable();
+    iter::from_fn(move || {
+        while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+        let first = lines.next()?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+            last = line;
         }
+        let start = first.as_ptr() as usize - text.as_ptr() as usize;
+        let end = last.as_ptr() as usize - text.as_ptr() as usize + last.len();
+        Some(&text[start..end])
+    })
+}
+
+/// Blocks of an input, see [`sections`].
+///
+/// Blocks can be accessed by index or, if their first line starts with a
+/// label like `WORDS:`, by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sections {
+    blocks: Vec<String>,
+}
+
+impl Sections {
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.blocks.get(index).map(String::as_str)
+    }
+
+    /// Returns the text after `name:` of the first block starting with it.
+    pub fn named(&self, name: &str) -> Option<&str> {
+        self.blocks
+            .iter()
+            .find_map(|block| block.strip_prefix(name)?.strip_prefix(':'))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.blocks.iter().map(String::as_str)
+    }
+}
+
+impl Index<usize> for Sections {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.blocks[index]
     }
-    res.push(module);
-    Ok(res)
 }
 
 pub fn debug_paths() {
@@ -113,4 +202,69 @@ mod tests {
     fn print_debug_paths() {
         debug_paths();
     }
+
+    #[test]
+    fn sections_lf() {
+        let res = sections("WORDS:THE,OWE\n\nAWAKEN\nTHE POWER\n");
+        assert_eq!(res.len(), 2);
+        assert_eq!(&res[0], "WORDS:THE,OWE");
+        assert_eq!(&res[1], "AWAKEN\nTHE POWER");
+    }
+
+    #[test]
+    fn sections_crlf() {
+        let res = sections("WORDS:THE,OWE\r\n\r\nAWAKEN\r\nTHE POWER\r\n");
+        assert_eq!(
+            res.iter().collect::<Vec<_>>(),
+            vec!["WORDS:THE,OWE", "AWAKEN\nTHE POWER"]
+        );
+    }
+
+    #[test]
+    fn sections_lone_cr() {
+        let res = sections("A\r\rB\rC");
+        assert_eq!(res.iter().collect::<Vec<_>>(), vec!["A", "B\nC"]);
+    }
+
+    #[test]
+    fn sections_missing_trailing_newline() {
+        let res = sections("A\n\nB");
+        assert_eq!(res.iter().collect::<Vec<_>>(), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn sections_multiple_blank_lines() {
+        let res = sections("\n\nA\n\n\n\nB\n\n\n");
+        assert_eq!(res.iter().collect::<Vec<_>>(), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn sections_trailing_whitespace() {
+        let res = sections("A  \nB\t\n \t \nC \r\n");
+        assert_eq!(res.iter().collect::<Vec<_>>(), vec!["A\nB", "C"]);
+    }
+
+    #[test]
+    fn blocks_are_subslices() {
+        let text = "A \r\nB\r\n \r\nC\rD";
+        let res: Vec<_> = blocks(text).collect();
+        assert_eq!(res, vec!["A \r\nB", "C\rD"]);
+        assert_eq!(res[1].as_ptr(), text[text.len() - 3..].as_ptr());
+    }
+
+    #[test]
+    fn sections_empty() {
+        assert!(sections("").is_empty());
+        assert!(sections("\r\n  \n").is_empty());
+    }
+
+    #[test]
+    fn sections_named() {
+        let res = sections("WORDS:THE,OWE\n\nNAILS:\n3\n4\n\nTEXT");
+        assert_eq!(res.named("WORDS"), Some("THE,OWE"));
+        assert_eq!(res.named("NAILS"), Some("\n3\n4"));
+        assert_eq!(res.named("WORD"), None);
+        assert_eq!(res.get(2), Some("TEXT"));
+        assert_eq!(res.get(3), None);
+    }
 }
@@ -1,4 +1,17 @@
+// Lets code generated by `#[derive(FromNote)]` refer to `::utils` in here too.
+extern crate self as utils;
+
+pub mod aho_corasick;
+pub mod answer;
+pub mod change;
 pub mod colors;
+pub mod cycle;
+pub mod error;
+pub mod event;
 pub mod grid;
 pub mod input;
+pub mod memo;
 pub mod parse;
+pub mod runner;
+
+pub use answer::Answer;
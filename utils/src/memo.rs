@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cache for a recursive function that is evaluated with an explicit stack
+/// instead of native recursion, so deep recursion cannot overflow the stack.
+///
+/// The function is given a [`Lookup`] to request the values of its
+/// subproblems. If a subproblem is not solved yet, `lookup.get(..)?` returns
+/// early, the subproblem is solved first and then the function is called
+/// again. The function should therefore be cheap to restart and must not
+/// depend on itself.
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+/// Read access to already solved subproblems while solving a [`Memo`].
+pub struct Lookup<'a, K, V> {
+    cache: &'a HashMap<K, V>,
+    missing: Option<K>,
+}
+
+/// Marker returned by [`Lookup::get`] for a subproblem that is not solved
+/// yet.
+#[derive(Debug)]
+pub struct Pending;
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached value for `key`, if it has been solved already.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Returns the value of `f` for `key`, solving and caching all
+    /// subproblems it depends on.
+    ///
+    /// Values cached by earlier calls are reused, so the same `f` should be
+    /// passed every time.
+    pub fn solve<F>(&mut self, key: K, mut f: F) -> V
+    where
+        F: FnMut(&K, &mut Lookup<K, V>) -> Result<V, Pending>,
+    {
+        let mut stack = vec![key.clone()];
+
+        while let Some(cur) = stack.last() {
+            if self.cache.contains_key(cur) {
+                stack.pop();
+                continue;
+            }
+            let mut lookup = Lookup {
+                cache: &self.cache,
+                missing: None,
+            };
+            match f(cur, &mut lookup) {
+                Ok(value) => {
+                    let cur = stack.pop().unwrap();
+                    self.cache.insert(cur, value);
+                }
+                Err(Pending) => {
+                    let missing = lookup
+                        .missing
+                        .expect("Pending returned without a missing subproblem");
+                    stack.push(missing);
+                }
+            }
+        }
+        self.cache[&key].clone()
+    }
+}
+
+impl<K, V> Default for Memo<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Lookup<'_, K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Returns the value of the subproblem `key`, or `Err(Pending)` if it has
+    /// to be solved first.
+    pub fn get(&mut self, key: &K) -> Result<V, Pending> {
+        match self.cache.get(key) {
+            Some(value) => Ok(value.clone()),
+            None => {
+                self.missing = Some(key.clone());
+                Err(Pending)
+            }
+        }
+    }
+}
+
+/// Bottom-up DP table over the subproblems `0, 1, 2, ...`.
+///
+/// The value for `n` is computed by `f(n, values)`, where `values` holds the
+/// solutions for all subproblems below `n`. The table grows on demand, so it
+/// can be reused for many targets of different size.
+pub struct Table<V, F> {
+    values: Vec<V>,
+    f: F,
+}
+
+impl<V, F> Table<V, F>
+where
+    F: Fn(usize, &[V]) -> V,
+{
+    pub fn new(f: F) -> Self {
+        Self {
+            values: Vec::new(),
+            f,
+        }
+    }
+
+    /// Returns the value for subproblem `n`, extending the table up to `n`
+    /// if needed.
+    pub fn get(&mut self, n: usize) -> &V {
+        while self.values.len() <= n {
+            let value = (self.f)(self.values.len(), &self.values);
+            self.values.push(value);
+        }
+        &self.values[n]
+    }
+
+    /// Returns all values computed so far.
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64, lookup: &mut Lookup<u64, u64>) -> Result<u64, Pending> {
+        if n < 2 {
+            return Ok(n);
+        }
+        Ok(lookup.get(&(n - 1))? + lookup.get(&(n - 2))?)
+    }
+
+    #[test]
+    fn fibonacci() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.solve(10, |&n, lookup| fib(n, lookup)), 55);
+        assert_eq!(memo.get(&9), Some(&34));
+        assert_eq!(memo.get(&11), None);
+    }
+
+    #[test]
+    fn reuses_cache() {
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        memo.solve(20, |&n, lookup| {
+            calls += 1;
+            fib(n, lookup)
+        });
+        let calls_first = calls;
+        memo.solve(20, |&n, lookup| {
+            calls += 1;
+            fib(n, lookup)
+        });
+        assert_eq!(calls, calls_first);
+    }
+
+    #[test]
+    fn deep_recursion() {
+        let mut memo = Memo::new();
+        let res = memo.solve(1_000_000u64, |&n, lookup| {
+            if n == 0 {
+                return Ok(0u64);
+            }
+            Ok(lookup.get(&(n - 1))? + n)
+        });
+        assert_eq!(res, 500_000_500_000);
+    }
+
+    #[test]
+    fn tuple_keys() {
+        // Number of lattice paths from (0, 0) to (row, col)
+        let mut memo = Memo::new();
+        let res = memo.solve((16, 16), |&(row, col): &(u32, u32), lookup| {
+            if row == 0 || col == 0 {
+                return Ok(1u64);
+            }
+            Ok(lookup.get(&(row - 1, col))? + lookup.get(&(row, col - 1))?)
+        });
+        assert_eq!(res, 601_080_390);
+    }
+
+    #[test]
+    fn table() {
+        let mut table = Table::new(|n, values: &[u64]| match n {
+            0 | 1 => n as u64,
+            _ => values[n - 1] + values[n - 2],
+        });
+        assert_eq!(*table.get(10), 55);
+        assert_eq!(table.values().len(), 11);
+        assert_eq!(*table.get(5), 5);
+        assert_eq!(table.values().len(), 11);
+        assert_eq!(*table.get(90), 2880067194370816120);
+    }
+}
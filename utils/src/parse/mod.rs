@@ -0,0 +1,216 @@
+pub mod note;
+
+use num_traits::{Float, Signed, Unsigned};
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Extracts all unsigned numbers, treating every non-digit as a separator.
+///
+/// Panics if a number does not fit into `T`, see [`try_numbers`].
+pub fn numbers<T>(s: &str) -> impl Iterator<Item = T> + '_
+where
+    T: FromStr + Unsigned,
+{
+    try_numbers(s).map(|res| res.unwrap_or_else(|err| panic!("{err}")))
+}
+
+/// Like [`numbers`], but yields an error with the position of each number that
+/// does not fit into `T` instead of panicking.
+pub fn try_numbers<T>(s: &str) -> impl Iterator<Item = Result<T, NumberError>> + '_
+where
+    T: FromStr + Unsigned,
+{
+    spans(s, false, false).map(|span| parse_span(s, span))
+}
+
+/// Extracts all signed integers. A `-` or `+` directly in front of a digit is
+/// part of the number, so `x=-5` yields `-5`.
+///
+/// Panics if a number does not fit into `T`, see [`try_ints`].
+pub fn ints<T>(s: &str) -> impl Iterator<Item = T> + '_
+where
+    T: FromStr + Signed,
+{
+    try_ints(s).map(|res| res.unwrap_or_else(|err| panic!("{err}")))
+}
+
+/// Like [`ints`], but yields an error with the position of each number that
+/// does not fit into `T` instead of panicking.
+pub fn try_ints<T>(s: &str) -> impl Iterator<Item = Result<T, NumberError>> + '_
+where
+    T: FromStr + Signed,
+{
+    spans(s, true, false).map(|span| parse_span(s, span))
+}
+
+/// Extracts all floating-point numbers with optional sign, fraction and
+/// exponent, e.g. `-1`, `2.5` or `1.5e-3`. A fraction needs digits on both
+/// sides of the `.`.
+pub fn floats<T>(s: &str) -> impl Iterator<Item = T> + '_
+where
+    T: FromStr + Float,
+{
+    // Too large values become infinity instead of failing.
+    spans(s, true, true).filter_map(|span| s[span].parse().ok())
+}
+
+/// A number in the input that does not fit into the requested type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberError {
+    /// Byte range of the number in the input.
+    pub span: Range<usize>,
+}
+
+impl fmt::Display for NumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "number at bytes {}..{} is out of range",
+            self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for NumberError {}
+
+fn parse_span<T: FromStr>(s: &str, span: Range<usize>) -> Result<T, NumberError> {
+    s[span.clone()].parse().map_err(|_| NumberError { span })
+}
+
+/// Returns the byte ranges of all numbers in `s`.
+fn spans(s: &str, sign: bool, float: bool) -> impl Iterator<Item = Range<usize>> + '_ {
+    let bytes = s.as_bytes();
+    let digits_from = move |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+    let digit_at = move |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    let mut pos = 0;
+
+    std::iter::from_fn(move || {
+        while pos < bytes.len() {
+            let start = pos;
+            let has_sign = sign && matches!(bytes[pos], b'-' | b'+');
+            if !digit_at(pos + has_sign as usize) {
+                pos += 1;
+                continue;
+            }
+            let mut end = digits_from(pos + has_sign as usize);
+            if float {
+                if bytes.get(end) == Some(&b'.') && digit_at(end + 1) {
+                    end = digits_from(end + 1);
+                }
+                if matches!(bytes.get(end), Some(b'e' | b'E')) {
+                    let exp_sign = matches!(bytes.get(end + 1), Some(b'-' | b'+'));
+                    if digit_at(end + 1 + exp_sign as usize) {
+                        end = digits_from(end + 1 + exp_sign as usize);
+                    }
+                }
+            }
+            pos = end;
+            return Some(start..end);
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leading_zeros() {
+        let nums: Vec<u32> = numbers("007 0042").collect();
+        assert_eq!(nums, vec![7, 42]);
+    }
+
+    #[test]
+    fn trailing_zeros() {
+        let nums: Vec<u32> = numbers("700 4200").collect();
+        assert_eq!(nums, vec![700, 4200]);
+    }
+
+    #[test]
+    fn newlines() {
+        let nums: Vec<u32> = numbers("12\n34\r\n56").collect();
+        assert_eq!(nums, vec![12, 34, 56]);
+    }
+
+    #[test]
+    fn mixed_content() {
+        let nums: Vec<u32> = numbers("12ab34cd56").collect();
+        assert_eq!(nums, vec![12, 34, 56]);
+    }
+
+    #[test]
+    fn empty_string() {
+        let nums: Vec<u32> = numbers("").collect();
+        assert!(nums.is_empty());
+    }
+
+    #[test]
+    fn no_numbers() {
+        let nums: Vec<u32> = numbers("abc def").collect();
+        assert!(nums.is_empty());
+    }
+
+    #[test]
+    fn numbers_ignore_sign() {
+        let nums: Vec<u32> = numbers("-5 +7").collect();
+        assert_eq!(nums, vec![5, 7]);
+    }
+
+    #[test]
+    fn try_numbers_overflow() {
+        let nums: Vec<Result<u8, _>> = try_numbers("12 256 3").collect();
+        assert_eq!(nums, vec![Ok(12), Err(NumberError { span: 3..6 }), Ok(3)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "number at bytes 0..3 is out of range")]
+    fn numbers_overflow_panics() {
+        numbers::<u8>("256").for_each(drop);
+    }
+
+    #[test]
+    fn ints_signs() {
+        let nums: Vec<i32> = ints("p=-5,+3 v=7,-0").collect();
+        assert_eq!(nums, vec![-5, 3, 7, 0]);
+    }
+
+    #[test]
+    fn ints_dash_between_numbers() {
+        let nums: Vec<i64> = ints("3-5 x--2 - 4").collect();
+        assert_eq!(nums, vec![3, -5, -2, 4]);
+    }
+
+    #[test]
+    fn ints_lone_signs() {
+        let nums: Vec<i32> = ints("- + -a 1-").collect();
+        assert_eq!(nums, vec![1]);
+    }
+
+    #[test]
+    fn try_ints_overflow() {
+        let nums: Vec<Result<i8, _>> = try_ints("-128 -129 127").collect();
+        assert_eq!(
+            nums,
+            vec![Ok(-128), Err(NumberError { span: 5..9 }), Ok(127)]
+        );
+    }
+
+    #[test]
+    fn floats_formats() {
+        let nums: Vec<f64> = floats("a=1.5, b=-2, c=+0.25 d=1e3 e=-2.5E-1").collect();
+        assert_eq!(nums, vec![1.5, -2.0, 0.25, 1000.0, -0.25]);
+    }
+
+    #[test]
+    fn floats_incomplete() {
+        let nums: Vec<f64> = floats("1. .5 2e x").collect();
+        assert_eq!(nums, vec![1.0, 5.0, 2.0]);
+    }
+}
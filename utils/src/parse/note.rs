@@ -0,0 +1,634 @@
+//! Parser combinators for the notes of Everybody Codes.
+//!
+//! A parser turns a [`Span`] of the note into a typed value. Combinators build
+//! larger parsers from smaller ones and keep track of where each piece is
+//! located, so errors point to the line and column of the offending text.
+
+use crate::grid::Grid;
+use crate::input;
+use std::fmt;
+use std::str::FromStr;
+
+pub use note_derive::FromNote;
+
+/// A piece of the note and its position in the whole note.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub text: &'a str,
+    /// Line of the first character, starting at 1.
+    pub line: usize,
+    /// Column of the first character in chars, starting at 1.
+    pub col: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// Types that can be parsed from a whole note, usually with
+/// `#[derive(FromNote)]`.
+pub trait FromNote: Sized {
+    fn from_note(note: &str) -> Result<Self>;
+}
+
+pub trait Parser<'a, T> {
+    fn parse(&self, span: Span<'a>) -> Result<T>;
+}
+
+impl<'a, T, P: Parser<'a, T>> Parser<'a, T> for &P {
+    fn parse(&self, span: Span<'a>) -> Result<T> {
+        (*self).parse(span)
+    }
+}
+
+/// Parser defined by a function, see [`from_fn`].
+#[derive(Clone, Copy)]
+pub struct FromFn<F>(F);
+
+impl<'a, T, F> Parser<'a, T> for FromFn<F>
+where
+    F: Fn(Span<'a>) -> Result<T>,
+{
+    fn parse(&self, span: Span<'a>) -> Result<T> {
+        (self.0)(span)
+    }
+}
+
+/// Creates a parser from a function.
+pub fn from_fn<'a, T, F>(f: F) -> FromFn<F>
+where
+    F: Fn(Span<'a>) -> Result<T>,
+{
+    FromFn(f)
+}
+
+pub trait ParserExt<'a, T>: Parser<'a, T> + Sized {
+    /// Converts the parsed value with `f`.
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+        from_fn(move |span: Span<'a>| self.parse(span).map(&f))
+    }
+
+    /// Converts the parsed value with `f`, which may reject it with a message.
+    fn try_map<U, E>(self, f: impl Fn(T) -> std::result::Result<U, E>) -> impl Parser<'a, U>
+    where
+        E: fmt::Display,
+    {
+        from_fn(move |span: Span<'a>| {
+            f(self.parse(span)?).map_err(|err| span.error(err.to_string()))
+        })
+    }
+}
+
+impl<'a, T, P: Parser<'a, T>> ParserExt<'a, T> for P {}
+
+impl<'a> Span<'a> {
+    /// Creates a span covering the whole note.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            line: 1,
+            col: 1,
+        }
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            col: self.col,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the span of `part`, which must be a subslice of this span's
+    /// text.
+    fn sub(&self, part: &'a str) -> Span<'a> {
+        let start = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        let before = &self.text[..start];
+        match before.rfind('\n') {
+            Some(newline) => Span {
+                text: part,
+                line: self.line + before.matches('\n').count(),
+                col: before[newline + 1..].chars().count() + 1,
+            },
+            None => Span {
+                text: part,
+                line: self.line,
+                col: self.col + before.chars().count(),
+            },
+        }
+    }
+
+    fn trim(&self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    fn lines(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.text.lines().map(|line| self.sub(line))
+    }
+
+    /// Splits into blocks separated by one or more blank lines.
+    ///
+    /// Blocks are split like [`input::sections`](crate::input::sections)
+    /// does, but keep their text as written.
+    fn sections(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        input::blocks(self.text).map(|block| self.sub(block))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.col, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` on the whole note.
+pub fn parse<'a, T>(note: &'a str, parser: impl Parser<'a, T>) -> Result<T> {
+    parser.parse(Span::new(note))
+}
+
+/// Returns the text as is.
+pub fn text<'a>() -> impl Parser<'a, &'a str> {
+    from_fn(|span: Span<'a>| Ok(span.text))
+}
+
+/// Parses the trimmed text with [`FromStr`].
+pub fn value<'a, T: FromStr>() -> impl Parser<'a, T> {
+    from_fn(|span: Span<'a>| {
+        let span = span.trim();
+        span.text
+            .parse()
+            .map_err(|_| span.error(format!("invalid value `{}`", span.text)))
+    })
+}
+
+/// Accepts a non-empty trimmed text without whitespace.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    from_fn(|span: Span<'a>| {
+        let span = span.trim();
+        if span.text.is_empty() {
+            Err(span.error("expected a word"))
+        } else if let Some(pos) = span.text.find(char::is_whitespace) {
+            Err(span.sub(&span.text[pos..]).error("unexpected whitespace"))
+        } else {
+            Ok(span.text)
+        }
+    })
+}
+
+/// Accepts exactly one char, ignoring surrounding whitespace.
+pub fn symbol<'a>() -> impl Parser<'a, char> {
+    from_fn(|span: Span<'a>| {
+        let span = span.trim();
+        let mut chars = span.text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(span.error(format!("expected a single symbol, found `{}`", span.text))),
+        }
+    })
+}
+
+/// Splits at every `sep` and parses each item.
+pub fn list<'a, T>(sep: char, item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    from_fn(move |span: Span<'a>| {
+        span.text
+            .split(sep)
+            .map(|part| item.parse(span.sub(part)))
+            .collect()
+    })
+}
+
+/// Splits at whitespace and parses each item.
+pub fn words<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    from_fn(move |span: Span<'a>| {
+        span.text
+            .split_whitespace()
+            .map(|part| item.parse(span.sub(part)))
+            .collect()
+    })
+}
+
+/// Splits at the first `sep` and parses both sides.
+pub fn key_value<'a, K, V>(
+    sep: &'a str,
+    key: impl Parser<'a, K>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    from_fn(move |span: Span<'a>| {
+        let (k, v) = span
+            .text
+            .split_once(sep)
+            .ok_or_else(|| span.error(format!("expected `{sep}`")))?;
+        Ok((key.parse(span.sub(k))?, value.parse(span.sub(v))?))
+    })
+}
+
+/// Expects the text to start with `prefix` and parses the rest.
+pub fn prefixed<'a, T>(prefix: &'a str, inner: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    from_fn(move |span: Span<'a>| {
+        let rest = span
+            .text
+            .strip_prefix(prefix)
+            .ok_or_else(|| span.error(format!("expected `{prefix}`")))?;
+        inner.parse(span.sub(rest))
+    })
+}
+
+/// Parses the rest of the first line that starts with `prefix`.
+pub fn find_prefixed<'a, T>(prefix: &'a str, inner: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    from_fn(move |span: Span<'a>| {
+        let line = span
+            .lines()
+            .find(|line| line.text.starts_with(prefix))
+            .ok_or_else(|| span.error(format!("expected a line starting with `{prefix}`")))?;
+        inner.parse(line.sub(&line.text[prefix.len()..]))
+    })
+}
+
+/// Parses the block of lines with the given index, blocks being separated by
+/// one or more blank lines.
+pub fn section<'a, T>(index: usize, inner: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    from_fn(move |span: Span<'a>| {
+        let section = span
+            .sections()
+            .nth(index)
+            .ok_or_else(|| span.error(format!("expected at least {} sections", index + 1)))?;
+        inner.parse(section)
+    })
+}
+
+/// Splits at every `delim` and parses the part with the given index.
+pub fn part<'a, T>(delim: &'a str, index: usize, inner: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    from_fn(move |span: Span<'a>| {
+        let part = span.text.split(delim).nth(index).ok_or_else(|| {
+            span.error(format!(
+                "expected at least {} parts separated by `{delim}`",
+                index + 1
+            ))
+        })?;
+        inner.parse(span.sub(part))
+    })
+}
+
+/// Parses each line.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    from_fn(move |span: Span<'a>| span.lines().map(|line| item.parse(line)).collect())
+}
+
+/// Parses each block of lines separated by one or more blank lines.
+pub fn sections<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    from_fn(move |span: Span<'a>| span.sections().map(|section| item.parse(section)).collect())
+}
+
+/// Parses the first block of lines with `head` and everything after the
+/// following blank lines with `body`.
+pub fn header<'a, H, B>(
+    head: impl Parser<'a, H>,
+    body: impl Parser<'a, B>,
+) -> impl Parser<'a, (H, B)> {
+    from_fn(move |span: Span<'a>| {
+        let first = span
+            .sections()
+            .next()
+            .ok_or_else(|| span.error("expected a header"))?;
+        let rest = &span.text
+            [first.text.as_ptr() as usize - span.text.as_ptr() as usize + first.text.len()..];
+        let rest = rest.trim_start_matches(['\n', '\r', ' ', '\t']);
+        Ok((head.parse(first)?, body.parse(span.sub(rest))?))
+    })
+}
+
+/// Parses a block whose first line is `label:`. The lines below the label
+/// are given to `inner`.
+pub fn labeled<'a, T>(label: &'a str, inner: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    from_fn(move |span: Span<'a>| {
+        let end = &span.text[span.text.len()..];
+        let (first, rest) = span.text.split_once('\n').unwrap_or((span.text, end));
+        match first.trim_end().strip_suffix(':') {
+            Some(found) if found == label => inner.parse(span.sub(rest)),
+            _ => Err(span.error(format!("expected label `{label}:`"))),
+        }
+    })
+}
+
+/// Parses whitespace separated columns, returning one vector per column.
+/// Every line must have the same number of columns.
+pub fn columns<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<Vec<T>>> {
+    from_fn(move |span: Span<'a>| {
+        let mut columns: Vec<Vec<T>> = Vec::new();
+
+        for (i, line) in span.lines().enumerate() {
+            let row = words(&item).parse(line)?;
+            if i == 0 {
+                columns.resize_with(row.len(), Vec::new);
+            } else if row.len() != columns.len() {
+                return Err(line.error(format!(
+                    "expected {} columns, found {}",
+                    columns.len(),
+                    row.len()
+                )));
+            }
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+        Ok(columns)
+    })
+}
+
+/// Parses the lines as a grid of chars.
+pub fn grid<'a>() -> impl Parser<'a, Grid<char>> {
+    from_fn(|span: Span<'a>| {
+        if span.text.trim().is_empty() {
+            return Err(span.error("expected a grid"));
+        }
+        Ok(Grid::from(span.text))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_error_at(err: ParseError, line: usize, col: usize) {
+        assert_eq!((err.line, err.col), (line, col), "{err}");
+    }
+
+    #[test]
+    fn value_trims() {
+        assert_eq!(parse(" 42 ", value::<u32>()), Ok(42));
+    }
+
+    #[test]
+    fn value_error_position() {
+        let err = parse("1,2,x", list(',', value::<u32>())).unwrap_err();
+        assert_error_at(err, 1, 5);
+    }
+
+    #[test]
+    fn word_rejects_whitespace() {
+        let err = parse("ab cd", word()).unwrap_err();
+        assert_error_at(err, 1, 3);
+    }
+
+    #[test]
+    fn symbol_rejects_long_text() {
+        assert_eq!(parse("+", symbol()), Ok('+'));
+        assert!(parse("++", symbol()).is_err());
+        assert!(parse("", symbol()).is_err());
+    }
+
+    #[test]
+    fn key_value_missing_separator() {
+        let err = parse("A,B", key_value(":", word(), word())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 1: expected `:`");
+    }
+
+    #[test]
+    fn lines_error_position() {
+        let err = parse("1 2\n3 x", lines(words(value::<u8>()))).unwrap_err();
+        assert_error_at(err, 2, 3);
+    }
+
+    #[test]
+    fn sections_multiple_blank_lines() {
+        let res = parse("a\nb\n\n\n\nc\n", sections(lines(word())));
+        assert_eq!(res, Ok(vec![vec!["a", "b"], vec!["c"]]));
+    }
+
+    #[test]
+    fn sections_like_input() {
+        let note = "a \r\nb\r\n \t\r\nc\r\n";
+        let res = parse(note, sections(lines(word())));
+        assert_eq!(res, Ok(vec![vec!["a", "b"], vec!["c"]]));
+        assert_eq!(input::sections(note).len(), 2);
+    }
+
+    #[test]
+    fn sections_error_position() {
+        let err = parse("1\n\n2\n3\nx", sections(lines(value::<u8>()))).unwrap_err();
+        assert_error_at(err, 5, 1);
+    }
+
+    #[test]
+    fn labeled_block() {
+        let res = parse("NAILS:\n3\n4", labeled("NAILS", lines(value::<u32>())));
+        assert_eq!(res, Ok(vec![3, 4]));
+        let err = parse("OTHER:\n3", labeled("NAILS", text())).unwrap_err();
+        assert_eq!(err.message, "expected label `NAILS:`");
+    }
+
+    #[test]
+    fn labeled_without_body() {
+        let res = parse("NAILS:", labeled("NAILS", text()));
+        assert_eq!(res, Ok(""));
+        let err = parse("NAILS:", labeled("NAILS", value::<u32>())).unwrap_err();
+        assert_error_at(err, 1, 7);
+    }
+
+    #[test]
+    fn columns_ragged() {
+        let err = parse("1 2\n3", columns(value::<u8>())).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected 2 columns, found 1"
+        );
+    }
+
+    #[test]
+    fn try_map_error_position() {
+        let even = value::<u32>().try_map(|n| if n % 2 == 0 { Ok(n) } else { Err("odd") });
+        let err = parse("2,4,5", list(',', even)).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 5: odd");
+    }
+
+    #[test]
+    fn multibyte_columns() {
+        let err = parse("é:ü,x y", key_value(":", word(), list(',', word()))).unwrap_err();
+        assert_error_at(err, 1, 6);
+    }
+
+    #[derive(Debug, PartialEq, FromNote)]
+    struct Runes {
+        #[note(prefix = "WORDS:", sep = ",")]
+        runes: Vec<String>,
+        #[note(section = 1, lines)]
+        inscription: Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq, FromNote)]
+    #[note(delim = ":")]
+    struct Plan {
+        #[note(part = 0)]
+        id: char,
+        #[note(part = 1, sep = ",")]
+        actions: Vec<char>,
+    }
+
+    #[derive(FromNote)]
+    struct Puzzle {
+        #[note(prefix = "SIZE:", words)]
+        size: Vec<usize>,
+        #[note(section = 1, grid)]
+        grid: Grid<char>,
+    }
+
+    /// Fields named like the locals of the generated `from_note`.
+    #[derive(Debug, PartialEq, FromNote)]
+    #[note(delim = "|")]
+    struct Shadowing {
+        #[note(part = 0)]
+        span: String,
+        #[note(part = 1)]
+        text: u32,
+        #[note(part = 2)]
+        note: char,
+    }
+
+    #[test]
+    fn derive_sections() {
+        let res = Runes::from_note("WORDS:THE,OWE\r\n\r\nAWAKEN THE\r\nPOWER  \r\n");
+        assert_eq!(
+            res,
+            Ok(Runes {
+                runes: vec!["THE".to_string(), "OWE".to_string()],
+                inscription: vec!["AWAKEN THE".to_string(), "POWER".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn derive_parts() {
+        let res = Plan::from_note("A:+,-,=");
+        assert_eq!(
+            res,
+            Ok(Plan {
+                id: 'A',
+                actions: vec!['+', '-', '='],
+            })
+        );
+    }
+
+    #[test]
+    fn derive_shadowing_names() {
+        let res = Shadowing::from_note("abc|42|x");
+        assert_eq!(
+            res,
+            Ok(Shadowing {
+                span: "abc".to_string(),
+                text: 42,
+                note: 'x',
+            })
+        );
+    }
+
+    #[test]
+    fn derive_grid() {
+        let res = Puzzle::from_note("SIZE: 2 3\n\n.#.\n#.#").unwrap();
+        assert_eq!(res.size, vec![2, 3]);
+        assert_eq!((res.grid.width, res.grid.height), (3, 2));
+    }
+
+    #[test]
+    fn derive_errors() {
+        let err = Runes::from_note("RUNES:THE\n\nAWAKEN").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected a line starting with `WORDS:`"
+        );
+        let err = Runes::from_note("WORDS:THE").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected at least 2 sections"
+        );
+        let err = Plan::from_note("A:+,--").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 5: invalid value `--`");
+        let err = Plan::from_note("A").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected at least 2 parts separated by `:`"
+        );
+    }
+
+    // quest02
+    #[test]
+    fn runes_and_inscription() {
+        let note = "WORDS:THE,OWE,MES\n\nAWAKEN THE POWE\nTHE FLAME";
+        let parser = header(prefixed("WORDS:", list(',', word())), lines(text()));
+        assert_eq!(
+            parse(note, parser),
+            Ok((
+                vec!["THE", "OWE", "MES"],
+                vec!["AWAKEN THE POWE", "THE FLAME"]
+            ))
+        );
+    }
+
+    #[test]
+    fn runes_and_grid() {
+        let note = "WORDS:THE,OWE\n\nHELWORLT\nENIGWDXL";
+        let (runes, grid) =
+            parse(note, header(prefixed("WORDS:", list(',', word())), grid())).unwrap();
+        assert_eq!(runes, vec!["THE", "OWE"]);
+        assert_eq!((grid.width, grid.height), (8, 2));
+    }
+
+    // quest06
+    #[test]
+    fn tree_branches() {
+        let note = "RR:A,B,C\nA:D,E\nB:F,@";
+        let parser = lines(key_value(":", word(), list(',', word())));
+        assert_eq!(
+            parse(note, parser),
+            Ok(vec![
+                ("RR", vec!["A", "B", "C"]),
+                ("A", vec!["D", "E"]),
+                ("B", vec!["F", "@"]),
+            ])
+        );
+    }
+
+    // quest07
+    #[test]
+    fn device_plans() {
+        let note = "A:+,-,=,=\nB:+,=,-,+";
+        let action = symbol().try_map(|c| match c {
+            '+' => Ok(1),
+            '-' => Ok(-1),
+            '=' => Ok(0),
+            _ => Err(format!("invalid action `{c}`")),
+        });
+        let parser = lines(key_value(":", symbol(), list(',', action)));
+        assert_eq!(
+            parse(note, &parser),
+            Ok(vec![('A', vec![1, -1, 0, 0]), ('B', vec![1, 0, -1, 1])])
+        );
+        let err = parse("A:+,-\nB:+,*", &parser).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 5: invalid action `*`");
+    }
+
+    // quest05
+    #[test]
+    fn dance_columns() {
+        let note = "2 3 4 5\n3 4 5 2\n4 5 2 3\n";
+        assert_eq!(
+            parse(note, columns(value::<usize>())),
+            Ok(vec![
+                vec![2, 3, 4],
+                vec![3, 4, 5],
+                vec![4, 5, 2],
+                vec![5, 2, 3],
+            ])
+        );
+    }
+}
@@ -0,0 +1,467 @@
+use crate::answer::Answer;
+use crate::colors::{GREEN, RED, RESET};
+use crate::error::{QuestError, Result};
+use crate::input;
+use std::any::Any;
+use std::env;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// The parts of a quest and the inputs to run them on.
+///
+/// Each call to [`Quest::part`] sets the solver for the inputs added after it,
+/// so a part can be registered more than once if its examples need different
+/// parameters than the real input.
+pub struct Quest {
+    solver: Option<(u8, Solver)>,
+    runs: Vec<Run>,
+    commands: Vec<Command>,
+}
+
+type Solver = Rc<dyn Fn(&str) -> Result<Answer>>;
+
+struct Run {
+    part: u8,
+    input: &'static str,
+    example: bool,
+    solve: Solver,
+    expected: Option<Answer>,
+}
+
+struct Command {
+    flag: &'static str,
+    options: &'static str,
+    handle: Box<dyn Fn(CommandArgs) -> CommandResult>,
+}
+
+/// Arguments of an extra command, see [`Quest::command`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandArgs {
+    pub part: u8,
+    /// Contents of the input file.
+    pub input: String,
+    /// The arguments after the input file.
+    pub options: Vec<String>,
+}
+
+/// Why an extra command failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The arguments don't fit the command, so its usage is printed.
+    Usage,
+    /// The command ran, but failed like a part can.
+    Quest(QuestError),
+}
+
+pub type CommandResult = std::result::Result<(), CommandError>;
+
+impl From<QuestError> for CommandError {
+    fn from(err: QuestError) -> Self {
+        CommandError::Quest(err)
+    }
+}
+
+impl Quest {
+    pub fn new() -> Self {
+        Self {
+            solver: None,
+            runs: Vec::new(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Sets the solver for the following inputs.
+    pub fn part<A, F>(mut self, part: u8, solve: F) -> Self
+    where
+        A: Into<Answer>,
+        F: Fn(&str) -> Result<A> + 'static,
+    {
+        let solve = move |input: &str| solve(input).map(Into::into);
+        self.solver = Some((part, Rc::new(solve)));
+        self
+    }
+
+    /// Adds an example input for the current part.
+    pub fn example(self, input: &'static str) -> Self {
+        self.add_run(input, true)
+    }
+
+    /// Adds the real input for the current part.
+    pub fn input(self, input: &'static str) -> Self {
+        self.add_run(input, false)
+    }
+
+    /// Sets the expected answer for the input added last.
+    pub fn expect(mut self, expected: impl Into<Answer>) -> Self {
+        let run = self
+            .runs
+            .last_mut()
+            .expect("No input to expect an answer for");
+        run.expected = Some(expected.into());
+        self
+    }
+
+    /// Adds an extra command that runs instead of the parts when `flag` is
+    /// given, e.g. to print how an answer comes about.
+    ///
+    /// The command is called as `<flag> <PART> <INPUT_FILE> <options>`, where
+    /// `options` only documents the arguments `handle` accepts after the input
+    /// file. Like the inputs of the parts, a relative `INPUT_FILE` is resolved
+    /// against the quest directory, not the working directory.
+    ///
+    /// If `handle` returns [`CommandError::Usage`], or the part or input file
+    /// is missing, the usage is printed and the process exits with status 2.
+    /// Other errors are printed and exit with status 1.
+    pub fn command<F>(mut self, flag: &'static str, options: &'static str, handle: F) -> Self
+    where
+        F: Fn(CommandArgs) -> CommandResult + 'static,
+    {
+        self.commands.push(Command {
+            flag,
+            options,
+            handle: Box::new(handle),
+        });
+        self
+    }
+
+    /// Runs all examples, then all real inputs, and prints the answers.
+    ///
+    /// A part that fails or panics is reported and the remaining parts still
+    /// run. Exits with a non-zero status if a part failed or an answer differs
+    /// from the expected one.
+    ///
+    /// The command line arguments `--part <N>` to only run one part and
+    /// `--report` to print [`Report`] lines instead are used by `ec all`.
+    /// The flag of a [`Quest::command`] runs that command instead.
+    pub fn run(mut self) {
+        let args: Vec<String> = env::args().skip(1).collect();
+        for (i, arg) in args.iter().enumerate() {
+            if let Some(command) = self.commands.iter().find(|command| command.flag == arg) {
+                process::exit(command.run(&args[i + 1..]));
+            }
+        }
+
+        let part: Option<u8> = match args.iter().position(|arg| arg == "--part") {
+            Some(i) => match args.get(i + 1).and_then(|part| part.parse().ok()) {
+                Some(part) => Some(part),
+                None => {
+                    eprintln!("--part needs a part number");
+                    process::exit(2);
+                }
+            },
+            None => None,
+        };
+        let report = args.iter().any(|arg| arg == "--report");
+
+        self.runs.sort_by_key(|run| (!run.example, run.part));
+        let mut failed = false;
+
+        for run in self
+            .runs
+            .iter()
+            .filter(|run| part.is_none_or(|p| p == run.part))
+        {
+            let res = run.report();
+            failed |= res.failed();
+            match report {
+                true => println!("{}", res.to_line()),
+                false => println!("{res}"),
+            }
+        }
+        if failed {
+            process::exit(1);
+        }
+    }
+
+    fn add_run(mut self, input: &'static str, example: bool) -> Self {
+        let (part, solve) = self.solver.clone().expect("No part to add an input to");
+        self.runs.push(Run {
+            part,
+            input,
+            example,
+            solve,
+            expected: None,
+        });
+        self
+    }
+}
+
+impl Command {
+    /// Runs the command on the arguments after its flag and returns the exit
+    /// status.
+    fn run(&self, args: &[String]) -> i32 {
+        let res = match args {
+            [part, file, options @ ..] => match part.parse() {
+                Ok(part) => input::try_read_file(file)
+                    .map_err(CommandError::from)
+                    .and_then(|input| {
+                        (self.handle)(CommandArgs {
+                            part,
+                            input,
+                            options: options.to_vec(),
+                        })
+                    }),
+                Err(_) => Err(CommandError::Usage),
+            },
+            _ => Err(CommandError::Usage),
+        };
+        match res {
+            Ok(()) => 0,
+            Err(CommandError::Usage) => {
+                eprintln!("{}", self.usage());
+                2
+            }
+            Err(CommandError::Quest(err)) => {
+                eprintln!("{err}");
+                1
+            }
+        }
+    }
+
+    fn usage(&self) -> String {
+        let options = match self.options {
+            "" => String::new(),
+            options => format!(" {options}"),
+        };
+        format!(
+            "Usage: {} <PART> <INPUT_FILE>{options}\n\
+             INPUT_FILE is relative to the quest directory.",
+            self.flag
+        )
+    }
+}
+
+impl Run {
+    fn report(&self) -> Report {
+        let start = Instant::now();
+        let answer = input::try_read_file(self.input)
+            .map(|input| panic::catch_unwind(AssertUnwindSafe(|| (self.solve)(&input))));
+        let time = start.elapsed();
+
+        let (status, answer) = match answer {
+            Err(err) | Ok(Ok(Err(err))) => (Status::Error, err.to_string()),
+            Ok(Err(payload)) => (Status::Panic, panic_message(payload.as_ref())),
+            Ok(Ok(Ok(answer))) => match &self.expected {
+                Some(expected) if *expected == answer => (Status::Ok, answer.to_string()),
+                Some(_) => (Status::Wrong, answer.to_string()),
+                None => (Status::Unchecked, answer.to_string()),
+            },
+        };
+        Report {
+            part: self.part,
+            example: self.example,
+            status,
+            answer,
+            expected: self.expected.as_ref().map(Answer::to_string),
+            time,
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(msg) => msg.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(msg) => msg.clone(),
+            None => "panicked".to_string(),
+        },
+    }
+}
+
+/// Outcome of running one part on one input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub part: u8,
+    pub example: bool,
+    pub status: Status,
+    /// The answer, or the error or panic message.
+    pub answer: String,
+    pub expected: Option<String>,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    /// The answer matches the expected one.
+    Ok,
+    /// There is no expected answer to check against.
+    Unchecked,
+    /// The answer differs from the expected one.
+    Wrong,
+    /// The part returned an error.
+    Error,
+    /// The part panicked.
+    Panic,
+}
+
+const REPORT_PREFIX: &str = "@report";
+
+impl Report {
+    pub fn failed(&self) -> bool {
+        matches!(self.status, Status::Wrong | Status::Error | Status::Panic)
+    }
+
+    /// Serializes the report to a single tab separated line.
+    pub fn to_line(&self) -> String {
+        let clean = |s: &str| s.replace(['\t', '\n', '\r'], " ");
+        format!(
+            "{REPORT_PREFIX}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.part,
+            if self.example { "example" } else { "input" },
+            self.status.name(),
+            self.time.as_micros(),
+            clean(&self.answer),
+            self.expected.as_deref().map(clean).unwrap_or_default(),
+        )
+    }
+
+    /// Parses a line written by [`Report::to_line`], or returns `None` for any
+    /// other line.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line
+            .strip_prefix(REPORT_PREFIX)?
+            .strip_prefix('\t')?
+            .split('\t');
+        let part = fields.next()?.parse().ok()?;
+        let example = match fields.next()? {
+            "example" => true,
+            "input" => false,
+            _ => return None,
+        };
+        let status = Status::from_name(fields.next()?)?;
+        let time = Duration::from_micros(fields.next()?.parse().ok()?);
+        let answer = fields.next()?.to_string();
+        let expected = Some(fields.next()?)
+            .filter(|expected| !expected.is_empty())
+            .map(str::to_string);
+        Some(Report {
+            part,
+            example,
+            status,
+            answer,
+            expected,
+            time,
+        })
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.example {
+            true => write!(f, "exercise {} (example): ", self.part)?,
+            false => write!(f, "exercise {}: ", self.part)?,
+        }
+        let answer = &self.answer;
+        match (self.status, &self.expected) {
+            (Status::Ok, _) => write!(f, "{answer} {GREEN}[ok]{RESET}"),
+            (Status::Wrong, Some(expected)) => {
+                write!(f, "{answer} {RED}[expected {expected}]{RESET}")
+            }
+            (Status::Error, _) => write!(f, "{RED}{answer}{RESET}"),
+            (Status::Panic, _) => write!(f, "{RED}panicked: {answer}{RESET}"),
+            _ => write!(f, "{answer}"),
+        }
+    }
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Unchecked => "unchecked",
+            Status::Wrong => "wrong",
+            Status::Error => "error",
+            Status::Panic => "panic",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            Status::Ok,
+            Status::Unchecked,
+            Status::Wrong,
+            Status::Error,
+            Status::Panic,
+        ]
+        .into_iter()
+        .find(|status| status.name() == name)
+    }
+}
+
+impl Default for Quest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_line() {
+        let report = Report {
+            part: 2,
+            example: true,
+            status: Status::Wrong,
+            answer: "RRB\t@".to_string(),
+            expected: Some("RRB@".to_string()),
+            time: Duration::from_micros(1234),
+        };
+        let line = report.to_line();
+        assert_eq!(line, "@report\t2\texample\twrong\t1234\tRRB @\tRRB@");
+        let parsed = Report::from_line(&line).unwrap();
+        assert_eq!(parsed.answer, "RRB @");
+        assert_eq!(parsed.expected, report.expected);
+        assert_eq!(parsed.time, report.time);
+        assert!(parsed.failed());
+    }
+
+    #[test]
+    fn report_line_other() {
+        assert_eq!(Report::from_line("35"), None);
+        assert_eq!(Report::from_line("@report\t1\tinput"), None);
+    }
+
+    #[test]
+    fn panicking_part() {
+        let run = Run {
+            part: 1,
+            input: "Cargo.toml",
+            example: false,
+            solve: Rc::new(|_: &str| -> Result<Answer> { panic!("Out of nails") }),
+            expected: None,
+        };
+        let report = run.report();
+        assert_eq!(report.status, Status::Panic);
+        assert_eq!(report.answer, "Out of nails");
+    }
+
+    #[test]
+    fn command() {
+        let command = Command {
+            flag: "--plan",
+            options: "[--csv]",
+            handle: Box::new(|args: CommandArgs| match args.options.as_slice() {
+                [] if args.part == 1 && args.input.contains("[package]") => Ok(()),
+                [] => Err(QuestError::invalid("No package").into()),
+                _ => Err(CommandError::Usage),
+            }),
+        };
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(command.run(&args(&["1", "Cargo.toml"])), 0);
+        assert_eq!(command.run(&args(&["2", "Cargo.toml"])), 1);
+        assert_eq!(command.run(&args(&["1", "missing.txt"])), 1);
+        assert_eq!(command.run(&args(&["1", "Cargo.toml", "--csv"])), 2);
+        assert_eq!(command.run(&args(&["one", "Cargo.toml"])), 2);
+        assert_eq!(command.run(&args(&["1"])), 2);
+        assert_eq!(
+            command.usage(),
+            "Usage: --plan <PART> <INPUT_FILE> [--csv]\n\
+             INPUT_FILE is relative to the quest directory."
+        );
+    }
+}